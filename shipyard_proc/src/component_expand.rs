@@ -6,10 +6,14 @@ pub(crate) fn expand_component(
     name: syn::Ident,
     generics: syn::Generics,
     attribute_input: Option<&syn::Attribute>,
+    shipyard_attr: Option<&syn::Attribute>,
 ) -> Result<TokenStream> {
+    let (clone, compare) = parse_shipyard_attr(shipyard_attr)?;
+    let clone = expand_clone(clone);
+
+    let mut track_modification = false;
     let tracking = if let Some(tracking_attr) = attribute_input {
         let mut track_insertion = false;
-        let mut track_modification = false;
         let mut track_deletion = false;
         let mut track_removal = false;
 
@@ -76,19 +80,89 @@ pub(crate) fn expand_component(
         syn::Ident::new("Untracked", Span::call_site())
     };
 
+    let compare = match compare {
+        Some(compare) if !track_modification => {
+            return Err(Error::new_spanned(
+                compare,
+                "compare requires modification tracking.",
+            ))
+        }
+        Some(_) => quote!(
+            const MODIFICATION_COMPARE: ::core::option::Option<
+                ::shipyard::ModificationCompare<Self>,
+            > = ::core::option::Option::Some(::shipyard::ModificationCompare::new());
+        ),
+        None => quote!(),
+    };
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote!(
         impl #impl_generics ::shipyard::Component for #name #ty_generics #where_clause {
             type Tracking = ::shipyard::track::#tracking;
+            #clone
+            #compare
         }
     ))
 }
 
-pub(crate) fn expand_unique(name: syn::Ident, generics: syn::Generics) -> TokenStream {
+pub(crate) fn expand_unique(
+    name: syn::Ident,
+    generics: syn::Generics,
+    shipyard_attr: Option<&syn::Attribute>,
+) -> Result<TokenStream> {
+    let (clone, compare) = parse_shipyard_attr(shipyard_attr)?;
+    if let Some(compare) = compare {
+        return Err(Error::new_spanned(
+            compare,
+            "compare is only supported for components.",
+        ));
+    }
+    let clone = expand_clone(clone);
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    quote!(
-        impl #impl_generics ::shipyard::Unique for #name #ty_generics #where_clause {}
-    )
+    Ok(quote!(
+        impl #impl_generics ::shipyard::Unique for #name #ty_generics #where_clause {
+            #clone
+        }
+    ))
+}
+
+/// Parses `#[shipyard(clone, compare)]`, returns if clone is present and the compare path if present.
+fn parse_shipyard_attr(
+    shipyard_attr: Option<&syn::Attribute>,
+) -> Result<(bool, Option<syn::Path>)> {
+    let mut clone = false;
+    let mut compare = None;
+
+    if let Some(shipyard_attr) = shipyard_attr {
+        shipyard_attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("clone") {
+                clone = true;
+
+                Ok(())
+            } else if meta.path.is_ident("compare") {
+                compare = Some(meta.path);
+
+                Ok(())
+            } else {
+                Err(meta.error("Only clone and compare are supported."))
+            }
+        })?;
+    }
+
+    Ok((clone, compare))
+}
+
+/// Generates the `CLONE` constant when `#[shipyard(clone)]` is present.
+fn expand_clone(clone: bool) -> TokenStream {
+    if clone {
+        quote!(
+            const CLONE: ::core::option::Option<fn(&Self) -> Self> =
+                ::core::option::Option::Some(<Self as ::core::clone::Clone>::clone);
+        )
+    } else {
+        quote!()
+    }
 }
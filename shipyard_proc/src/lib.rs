@@ -14,13 +14,27 @@ use into_iter_expand::expand_into_iter;
 use label_expand::expand_label;
 use world_borrow_expand::expand_world_borrow;
 
-#[proc_macro_derive(Component, attributes(track))]
+#[proc_macro_derive(Component, attributes(track, shipyard))]
 pub fn component(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(item as syn::DeriveInput);
 
     let name = input.ident;
     let generics = input.generics;
 
+    let shipyard_attr: Option<&syn::Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| match attr.style {
+            syn::AttrStyle::Outer => true,
+            syn::AttrStyle::Inner(_) => false,
+        })
+        .find(|attr| {
+            attr.path()
+                .get_ident()
+                .map(|ident| ident == "shipyard")
+                .unwrap_or(false)
+        });
+
     let attribute_input: Option<&syn::Attribute> = input
         .attrs
         .iter()
@@ -35,19 +49,35 @@ pub fn component(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 .unwrap_or(false)
         });
 
-    expand_component(name, generics, attribute_input)
+    expand_component(name, generics, attribute_input, shipyard_attr)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
 
-#[proc_macro_derive(Unique)]
+#[proc_macro_derive(Unique, attributes(shipyard))]
 pub fn unique(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(item as syn::DeriveInput);
 
     let name = input.ident;
     let generics = input.generics;
 
-    expand_unique(name, generics).into()
+    let shipyard_attr: Option<&syn::Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| match attr.style {
+            syn::AttrStyle::Outer => true,
+            syn::AttrStyle::Inner(_) => false,
+        })
+        .find(|attr| {
+            attr.path()
+                .get_ident()
+                .map(|ident| ident == "shipyard")
+                .unwrap_or(false)
+        });
+
+    expand_unique(name, generics, shipyard_attr)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
 
 #[proc_macro_derive(WorldBorrow, attributes(shipyard))]
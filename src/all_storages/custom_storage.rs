@@ -337,7 +337,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new(f())
+                    })
                     .0
             }
             .borrow()
@@ -404,7 +410,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new_non_send(f(), self.thread_id_generator.clone())
+                    })
                     .0
             }
             .borrow()
@@ -463,7 +475,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new_non_sync(f())
+                    })
                     .0
             }
             .borrow()
@@ -531,6 +549,10 @@ impl CustomStorageAccess for AllStorages {
                 &*storages
                     .entry(storage_id)
                     .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
                         SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
                     })
                     .0
@@ -589,7 +611,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new(f())
+                    })
                     .0
             }
             .borrow_mut()
@@ -656,7 +684,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new_non_send(f(), self.thread_id_generator.clone())
+                    })
                     .0
             }
             .borrow_mut()
@@ -715,7 +749,13 @@ impl CustomStorageAccess for AllStorages {
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
+                    .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
+                        SBox::new_non_sync(f())
+                    })
                     .0
             }
             .borrow_mut()
@@ -783,6 +823,10 @@ impl CustomStorageAccess for AllStorages {
                 &*storages
                     .entry(storage_id)
                     .or_insert_with(|| {
+                        if let Some(on_storage_registered) = &self.on_storage_registered {
+                            on_storage_registered(storage_id, type_name::<S>());
+                        }
+
                         SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
                     })
                     .0
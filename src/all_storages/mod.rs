@@ -1,11 +1,14 @@
 mod custom_storage;
 mod delete_any;
 mod retain;
+mod seeded_hasher;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
 pub use retain::TupleRetainStorage;
 
+pub(crate) use seeded_hasher::SeededHasher;
+
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::Borrow;
 use crate::component::{Component, Unique};
@@ -15,27 +18,36 @@ use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
 use crate::iter::{ShiperatorCaptain, ShiperatorSailor};
 use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
-use crate::memory_usage::AllStoragesMemoryUsage;
+use crate::memory_usage::{AllStoragesMemoryUsage, StorageMemoryReport};
 use crate::public_transport::RwLock;
 use crate::public_transport::ShipyardRwLock;
 use crate::r#mut::Mut;
 use crate::reserve::BulkEntityIter;
-use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::sparse_set::{
+    BulkAddEntity, SparseSet, SpawnColumns, TupleAddComponent, TupleDelete, TupleRemove,
+};
 #[cfg(feature = "std")]
 use crate::std_thread_id_generator;
 use crate::storage::{SBox, Storage, StorageId};
 use crate::system::AllSystem;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
 use crate::unique::UniqueStorage;
-use crate::views::EntitiesViewMut;
+use crate::views::{EntitiesView, EntitiesViewMut, UniqueViewMut};
+#[cfg(feature = "std")]
+use crate::world::World;
 use crate::{error, ShipHashMap};
+#[cfg(feature = "std")]
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::type_name;
 use core::hash::BuildHasherDefault;
 use core::marker::PhantomData;
 use core::sync::atomic::AtomicU64;
 use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
 
 #[allow(missing_docs)]
 pub struct MissingLock;
@@ -49,6 +61,9 @@ pub struct ThreadIdPresent;
 pub(crate) struct AllStoragesBuilder<Lock, ThreadId> {
     custom_lock: Option<Box<dyn ShipyardRwLock + Send + Sync>>,
     custom_thread_id: Option<Arc<dyn Fn() -> u64 + Send + Sync>>,
+    seed: Option<u64>,
+    #[cfg(feature = "entity_tag")]
+    entity_tag: u8,
     _phantom: PhantomData<(Lock, ThreadId)>,
 }
 
@@ -58,6 +73,9 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: Some(Arc::new(std_thread_id_generator)),
+            seed: None,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: 0,
             _phantom: PhantomData,
         }
     }
@@ -67,6 +85,9 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: None,
+            seed: None,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: 0,
             _phantom: PhantomData,
         }
     }
@@ -76,6 +97,9 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: None,
             custom_thread_id: None,
+            seed: None,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: 0,
             _phantom: PhantomData,
         }
     }
@@ -86,6 +110,9 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: Some(L::new()),
             custom_thread_id: self.custom_thread_id,
+            seed: self.seed,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: self.entity_tag,
             _phantom: PhantomData,
         }
     }
@@ -98,16 +125,36 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         AllStoragesBuilder {
             custom_lock: self.custom_lock,
             custom_thread_id: Some(Arc::new(thread_id)),
+            seed: self.seed,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: self.entity_tag,
             _phantom: PhantomData,
         }
     }
+
+    pub(crate) fn with_seed(mut self, seed: u64) -> AllStoragesBuilder<Lock, ThreadId> {
+        self.seed = Some(seed);
+
+        self
+    }
+
+    #[cfg(feature = "entity_tag")]
+    pub(crate) fn with_entity_tag(mut self, tag: u8) -> AllStoragesBuilder<Lock, ThreadId> {
+        self.entity_tag = tag;
+
+        self
+    }
 }
 
 impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
     pub(crate) fn build(self, counter: Arc<AtomicU64>) -> AtomicRefCell<AllStorages> {
-        let mut storages = ShipHashMap::with_hasher(BuildHasherDefault::default());
+        let mut storages = HashMap::with_hasher(SeededHasher::new(self.seed));
 
-        storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
+        #[cfg(not(feature = "entity_tag"))]
+        let entities = Entities::new();
+        #[cfg(feature = "entity_tag")]
+        let entities = Entities::new_tagged(self.entity_tag);
+        storages.insert(StorageId::of::<Entities>(), SBox::new(entities));
 
         let storages = if let Some(custom_lock) = self.custom_lock {
             RwLock::new_custom(custom_lock, storages)
@@ -135,13 +182,20 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
                     main_thread_id,
                     thread_id_generator: thread_id_generator.clone(),
                     counter,
+                    entity_names: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+                    on_storage_registered: None,
                 },
                 thread_id_generator,
             )
         }
         #[cfg(not(feature = "thread_local"))]
         {
-            AtomicRefCell::new(AllStorages { storages, counter })
+            AtomicRefCell::new(AllStorages {
+                storages,
+                counter,
+                entity_names: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+                on_storage_registered: None,
+            })
         }
     }
 }
@@ -155,12 +209,15 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
 // so any access to storages are valid as long as the World exists
 // we use a HashMap, it can reallocate, but even in this case the storages won't move since they are boxed
 pub struct AllStorages {
-    pub(crate) storages: RwLock<ShipHashMap<StorageId, SBox>>,
+    pub(crate) storages: RwLock<HashMap<StorageId, SBox, SeededHasher>>,
     #[cfg(feature = "thread_local")]
     main_thread_id: u64,
     #[cfg(feature = "thread_local")]
     thread_id_generator: Arc<dyn Fn() -> u64 + Send + Sync>,
     counter: Arc<AtomicU64>,
+    entity_names: ShipHashMap<EntityId, String>,
+    #[allow(clippy::type_complexity)]
+    on_storage_registered: Option<Box<dyn Fn(StorageId, &'static str) + Send + Sync>>,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -171,7 +228,7 @@ unsafe impl Sync for AllStorages {}
 impl AllStorages {
     #[cfg(feature = "std")]
     pub(crate) fn new(counter: Arc<AtomicU64>) -> Self {
-        let mut storages = ShipHashMap::with_hasher(BuildHasherDefault::default());
+        let mut storages = HashMap::with_hasher(SeededHasher::default());
 
         storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
 
@@ -182,6 +239,8 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id_generator: Arc::new(std_thread_id_generator),
             counter,
+            entity_names: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+            on_storage_registered: None,
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -214,6 +273,45 @@ impl AllStorages {
                 self.get_tracking_timestamp(),
             )));
     }
+    /// Returns an exclusive view over the unique storage `T`, adding it with the result of `f` if it doesn't exist yet.\
+    /// `f` is only called when the storage is missing.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, World};
+    ///
+    /// #[derive(Unique, Default)]
+    /// struct Config(u32);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.get_or_add_unique(Config::default).unwrap().0 = 5;
+    ///
+    /// assert_eq!(all_storages.get_or_add_unique(|| Config(10)).unwrap().0, 5);
+    /// ```
+    pub fn get_or_add_unique<T: Send + Sync + Unique, F: FnOnce() -> T>(
+        &self,
+        f: F,
+    ) -> Result<UniqueViewMut<'_, T>, error::GetStorage> {
+        self.add_unique_with(f);
+
+        self.borrow::<UniqueViewMut<'_, T>>()
+    }
+    /// Adds the unique storage `T` with the result of `f` if it doesn't exist yet.
+    pub(crate) fn add_unique_with<T: Send + Sync + Unique, F: FnOnce() -> T>(&self, f: F) {
+        let storage_id = StorageId::of::<UniqueStorage<T>>();
+
+        self.storages
+            .write()
+            .entry(storage_id)
+            .or_insert_with(|| SBox::new(UniqueStorage::new(f(), self.get_tracking_timestamp())));
+    }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.
@@ -351,27 +449,94 @@ impl AllStorages {
     /// });
     /// ```
     pub fn delete_entity(&mut self, entity: EntityId) -> bool {
+        #[cfg(feature = "hierarchy")]
+        self.unlink_hierarchy(entity);
+
         // no need to lock here since we have a unique access
         let mut entities = self.entities_mut().unwrap();
 
         if entities.delete_unchecked(entity) {
             drop(entities);
 
-            self.strip(entity);
+            let current = self.get_current();
+
+            for storage in self.storages.get_mut().values_mut() {
+                unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
+            }
+
+            self.entity_names.remove(&entity);
 
             true
         } else {
             false
         }
     }
-    /// Deletes all components from an entity without deleting it.
+    /// Creates a new entity with a copy of all `entity`'s components.\
+    /// Components are copied using [`Component::CLONE`].
+    ///
+    /// Nothing is copied if any component can't be cloned, the error lists their storages.
     ///
     /// ### Example
     ///
     /// ```
     /// use shipyard::{AllStoragesViewMut, Component, World};
     ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// #[shipyard(clone)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    /// let clone = all_storages.clone_entity(entity).unwrap();
+    ///
+    /// assert_ne!(entity, clone);
+    /// assert_eq!(*all_storages.get::<&U32>(clone).unwrap(), U32(0));
+    /// ```
+    pub fn clone_entity(&mut self, entity: EntityId) -> Result<EntityId, error::CloneEntity> {
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return Err(error::CloneEntity::EntityIsNotAlive);
+        }
+
+        let not_clone = self
+            .storages
+            .get_mut()
+            .values_mut()
+            .map(|storage| unsafe { &mut *storage.0 }.get_mut())
+            .filter(|storage| !storage.is_component_clonable(entity))
+            .map(|storage| storage.name())
+            .collect::<Vec<_>>();
+
+        if !not_clone.is_empty() {
+            return Err(error::CloneEntity::NotClone(not_clone));
+        }
+
+        let current = self.get_current();
+        let clone = self.exclusive_storage_mut::<Entities>().unwrap().generate();
+
+        for storage in self.storages.get_mut().values_mut() {
+            unsafe { &mut *storage.0 }
+                .get_mut()
+                .clone_component(entity, clone, current);
+        }
+
+        Ok(clone)
+    }
+    /// Removes all components from an entity without deleting it.\
+    /// The entity stays alive with the same `EntityId`, storages tracking removal record it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, View, World};
+    ///
     /// #[derive(Component)]
+    /// #[track(Removal)]
     /// struct U32(u32);
     ///
     /// #[derive(Component)]
@@ -383,14 +548,70 @@ impl AllStorages {
     /// let entity = all_storages.add_entity((U32(0), USIZE(1)));
     ///
     /// all_storages.strip(entity);
+    ///
+    /// assert!(all_storages.is_entity_alive(entity));
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.removed().collect::<Vec<_>>(), [entity]);
+    /// });
     /// ```
     #[track_caller]
     pub fn strip(&mut self, entity: EntityId) {
         let current = self.get_current();
 
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
+            unsafe { &mut *storage.0 }.get_mut().strip(entity, current);
+        }
+    }
+    /// Deletes all entities for which `f` returns `false` and all their components.\
+    /// Returns the number of entities deleted.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity0 = all_storages.add_entity(U32(0));
+    /// let entity1 = all_storages.add_entity(U32(1));
+    /// let entity2 = all_storages.add_entity(());
+    ///
+    /// assert_eq!(all_storages.retain_entities(|entity| entity == entity1), 2);
+    ///
+    /// assert!(!all_storages.is_entity_alive(entity0));
+    /// assert!(!all_storages.is_entity_alive(entity2));
+    ///
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert!(u32s.get(entity0).is_err());
+    ///     assert_eq!(u32s.get(entity1), Ok(&U32(1)));
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn retain_entities<F: FnMut(EntityId) -> bool>(&mut self, mut f: F) -> usize {
+        let len = self.exclusive_storage_mut::<Entities>().unwrap().data.len() as u64;
+        let mut deleted = 0;
+
+        // deleting an entity doesn't move the others, they can be deleted during the walk
+        for index in 0..len {
+            let entity = self
+                .exclusive_storage_mut::<Entities>()
+                .unwrap()
+                .iter_range(index..index + 1)
+                .next();
+
+            if let Some(entity) = entity {
+                if !f(entity) {
+                    self.delete_entity(entity);
+                    deleted += 1;
+                }
+            }
         }
+
+        deleted
     }
     /// Deletes all components of an entity except the ones passed in `S`.  
     /// The storage's type has to be used and not the component.  
@@ -446,10 +667,49 @@ impl AllStorages {
     pub fn clear(&mut self) {
         let current = self.get_current();
 
+        self.entity_names.clear();
+
         for storage in self.storages.get_mut().values_mut() {
             unsafe { &mut *storage.0 }.get_mut().clear(current);
         }
     }
+    /// Deletes all entities and components, storages stay registered and uniques are kept.
+    ///
+    /// When `reset_ids` is `true`, the next entities get the same `EntityId`s as in a new `World`,
+    /// starting at index `0` with generation `0`.\
+    /// Deletion and removal tracking data is also cleared since it would refer to reused `EntityId`s.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, EntityId, Unique, UniqueView, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Level(u32);
+    ///
+    /// #[derive(Unique)]
+    /// struct Score(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique(Score(10));
+    /// all_storages.add_entity(Level(0));
+    ///
+    /// all_storages.clear_entities(true);
+    ///
+    /// assert_eq!(all_storages.add_entity(Level(1)), EntityId::new_from_index_and_gen(0, 0));
+    /// assert_eq!(all_storages.borrow::<UniqueView<Score>>().unwrap().0, 10);
+    /// ```
+    #[track_caller]
+    pub fn clear_entities(&mut self, reset_ids: bool) {
+        self.clear();
+
+        if reset_ids {
+            self.exclusive_storage_mut::<Entities>().unwrap().reset();
+            self.clear_all_removed_and_deleted();
+        }
+    }
     /// Clear all deletion and removal tracking data.
     #[track_caller]
     pub fn clear_all_removed_and_deleted(&mut self) {
@@ -555,6 +815,37 @@ impl AllStorages {
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
         source.bulk_add_entity(self)
     }
+    /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.\
+    /// `columns` is a tuple of iterators, each one yielding the components of a single storage.\
+    /// All columns have to yield the same number of components and a different component type, otherwise no entity is created and an error is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let new_entities = all_storages
+    ///     .spawn_columns(((0..10).map(U32), (10..20).map(USIZE)))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(new_entities.len(), 10);
+    /// ```
+    #[inline]
+    pub fn spawn_columns<T: SpawnColumns>(
+        &mut self,
+        columns: T,
+    ) -> Result<BulkEntityIter<'_>, error::SpawnColumns> {
+        columns.spawn_columns(self)
+    }
     /// Adds components to an existing entity.  
     /// If the entity already owned a component it will be replaced.  
     /// `component` must always be a tuple, even for a single component.  
@@ -624,6 +915,35 @@ impl AllStorages {
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
         C::delete(self, entity);
     }
+    /// Deletes `entity`'s component from the storage identified by `storage_id`.\
+    /// Does nothing if the storage doesn't exist or `entity` doesn't have a component in it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, sparse_set::SparseSet, StorageId, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    ///
+    /// all_storages.delete_component_by_id(entity, StorageId::of::<SparseSet<U32>>());
+    ///
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert!(!u32s.contains(entity));
+    /// });
+    /// ```
+    pub fn delete_component_by_id(&mut self, entity: EntityId, storage_id: StorageId) {
+        let current = self.get_current();
+
+        if let Some(storage) = self.storages.get_mut().get_mut(&storage_id) {
+            unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
+        }
+    }
     /// Removes components from an entity.  
     /// `C` must always be a tuple, even for a single component.
     ///
@@ -660,7 +980,7 @@ You can use:
 * [EntitiesViewMut] for an exclusive reference to the entity storage
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -766,7 +1086,7 @@ You can use:
 * [EntitiesViewMut] for an exclusive reference to the entity storage
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -864,7 +1184,7 @@ You can use:
 * [EntitiesViewMut] for an exclusive reference to the entity storage
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -1009,6 +1329,9 @@ let i = all_storages.run(sys1);
     pub fn delete_any<T: TupleDeleteAny>(&mut self) {
         T::delete_any(self);
     }
+    pub(crate) fn contains_storage(&self, storage_id: StorageId) -> bool {
+        self.storages.read().contains_key(&storage_id)
+    }
     pub(crate) fn entities(&self) -> Result<ARef<'_, &'_ Entities>, error::GetStorage> {
         let storage_id = StorageId::of::<Entities>();
 
@@ -1076,7 +1399,13 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new(f()))
+                .or_insert_with(|| {
+                    if let Some(on_storage_registered) = &self.on_storage_registered {
+                        on_storage_registered(storage_id, type_name::<T>());
+                    }
+
+                    SBox::new(f())
+                })
                 .0
         }
         .get_mut()
@@ -1084,6 +1413,72 @@ let i = all_storages.run(sys1);
         .downcast_mut()
         .unwrap()
     }
+    /// Inserts a storage copied from another `World`, replacing any storage with the same id.
+    #[cfg(not(feature = "thread_local"))]
+    pub(crate) fn insert_cloned_storage<T: 'static + Storage + Send + Sync>(
+        &mut self,
+        storage_id: StorageId,
+        storage: T,
+    ) {
+        self.storages
+            .get_mut()
+            .insert(storage_id, SBox::new(storage));
+    }
+    /// Inserts a storage copied from another `World`, replacing any storage with the same id.
+    ///
+    /// The storage is inserted as `!Send` and `!Sync`, [`AllStorages::clone_storages_to`] then copies the original thread constraints.
+    #[cfg(feature = "thread_local")]
+    pub(crate) fn insert_cloned_storage<T: 'static + Storage>(
+        &mut self,
+        storage_id: StorageId,
+        storage: T,
+    ) {
+        self.storages.get_mut().insert(
+            storage_id,
+            SBox::new_non_send_sync(storage, self.thread_id_generator.clone()),
+        );
+    }
+    /// Copies all storages to `other`.
+    pub(crate) fn clone_storages_to(
+        &self,
+        other: &mut AllStorages,
+    ) -> Result<(), error::CloneWorld> {
+        let storages = self.storages.read();
+
+        for (&storage_id, sbox) in storages.iter() {
+            let cell = unsafe { &*sbox.0 };
+            let storage = cell
+                .borrow()
+                .map_err(|borrow| error::CloneWorld::StorageBorrow {
+                    id: storage_id,
+                    borrow,
+                })?;
+
+            if !storage.clone_storage_to(other, storage_id) {
+                return Err(error::CloneWorld::NotClone {
+                    name: storage.name(),
+                    id: storage_id,
+                });
+            }
+
+            #[cfg(feature = "thread_local")]
+            if let Some(other_sbox) = other.storages.get_mut().get(&storage_id) {
+                unsafe { &mut *other_sbox.0 }.copy_thread_constraints(cell);
+            }
+        }
+
+        Ok(())
+    }
+    /// Returns `false` if the storage doesn't exist.
+    pub(crate) fn set_frozen_by_id(&mut self, storage_id: StorageId, frozen: bool) -> bool {
+        if let Some(storage) = self.storages.get_mut().get_mut(&storage_id) {
+            unsafe { &mut *storage.0 }.set_frozen(frozen);
+
+            true
+        } else {
+            false
+        }
+    }
     #[cfg(feature = "thread_local")]
     #[track_caller]
     pub(crate) fn exclusive_storage_or_insert_non_send_mut<T, F>(
@@ -1100,7 +1495,13 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
+                .or_insert_with(|| {
+                    if let Some(on_storage_registered) = &self.on_storage_registered {
+                        on_storage_registered(storage_id, type_name::<T>());
+                    }
+
+                    SBox::new_non_send(f(), self.thread_id_generator.clone())
+                })
                 .0
         }
         .get_mut()
@@ -1123,7 +1524,13 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_sync(f()))
+                .or_insert_with(|| {
+                    if let Some(on_storage_registered) = &self.on_storage_registered {
+                        on_storage_registered(storage_id, type_name::<T>());
+                    }
+
+                    SBox::new_non_sync(f())
+                })
                 .0
         }
         .get_mut()
@@ -1147,7 +1554,13 @@ let i = all_storages.run(sys1);
         unsafe {
             &mut *storages
                 .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send_sync(f(), self.thread_id_generator.clone()))
+                .or_insert_with(|| {
+                    if let Some(on_storage_registered) = &self.on_storage_registered {
+                        on_storage_registered(storage_id, type_name::<T>());
+                    }
+
+                    SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                })
                 .0
         }
         .get_mut()
@@ -1164,10 +1577,99 @@ let i = all_storages.run(sys1);
             .unwrap()
             .spawn(entity)
     }
+    /// Returns the number of components in the storage identified by `storage_id`.\
+    /// For [`Entities`] it is the number of living entities and for unique storages it is always 1.
+    ///
+    /// Returns `None` if the storage doesn't exist, is exclusively borrowed or doesn't report its length.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{sparse_set::SparseSet, AllStoragesViewMut, Component, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity(Position(0.0, 0.0));
+    /// all_storages.add_entity(Position(1.0, 1.0));
+    ///
+    /// let storage_id = StorageId::of::<SparseSet<Position>>();
+    /// assert_eq!(all_storages.storage_len(storage_id), Some(2));
+    /// ```
+    pub fn storage_len(&self, storage_id: StorageId) -> Option<usize> {
+        let storages = self.storages.read();
+        let storage = storages.get(&storage_id)?;
+
+        unsafe { &*storage.0 }.borrow().ok()?.len()
+    }
     /// Displays storages memory information.
     pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
         AllStoragesMemoryUsage(self)
     }
+    /// Returns the memory usage of each storage.\
+    /// Storages currently borrowed exclusively and storages not reporting their memory usage are skipped.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity(U32(0));
+    ///
+    /// let report = all_storages.memory_report();
+    /// let u32s = report
+    ///     .iter()
+    ///     .find(|report| report.usage.storage_name.contains("U32"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(u32s.usage.component_count, 1);
+    /// assert!(u32s.usage.used_memory_bytes <= u32s.usage.allocated_memory_bytes);
+    ///
+    /// let used = u32s.used.as_ref().unwrap();
+    /// assert_eq!(used.data, core::mem::size_of::<U32>());
+    /// assert!(used.sparse > 0);
+    /// ```
+    pub fn memory_report(&self) -> Vec<StorageMemoryReport> {
+        let storages = self.storages.read();
+
+        storages
+            .values()
+            .filter_map(|storage| {
+                let storage = unsafe { &*storage.0 }.borrow().ok()?;
+
+                if let Some(breakdown) = storage.memory_breakdown() {
+                    Some(StorageMemoryReport {
+                        usage: breakdown.base,
+                        used: Some(breakdown.used),
+                        allocated: Some(breakdown.allocated),
+                    })
+                } else {
+                    Some(StorageMemoryReport {
+                        usage: storage.memory_usage()?,
+                        used: None,
+                        allocated: None,
+                    })
+                }
+            })
+            .collect()
+    }
+    /// Releases unused memory in all storages, returns the number of bytes freed.\
+    /// See [`SparseSet::shrink_to_fit`] for details.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        self.storages
+            .get_mut()
+            .values_mut()
+            .map(|storage| unsafe { &mut *storage.0 }.get_mut().shrink_to_fit())
+            .sum()
+    }
 
     #[inline]
     pub(crate) fn get_current(&self) -> TrackingTimestamp {
@@ -1211,6 +1713,70 @@ let i = all_storages.run(sys1);
         T::track_all(self);
     }
 
+    /// Creates the storage of `T` if it doesn't exist yet.\
+    /// Returns `true` if the storage was created.
+    pub fn register<T: Send + Sync + Component>(&mut self) -> bool {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+        let is_new = !self.storages.get_mut().contains_key(&storage_id);
+
+        self.exclusive_storage_or_insert_mut(storage_id, SparseSet::<T>::new);
+
+        is_new
+    }
+
+    /// Creates the storage of `T` if it doesn't exist yet and reserves memory for at least `capacity` components.\
+    /// Returns `true` if the storage was created.
+    pub fn register_with_capacity<T: Send + Sync + Component>(&mut self, capacity: usize) -> bool {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+        let is_new = !self.storages.get_mut().contains_key(&storage_id);
+
+        self.exclusive_storage_or_insert_mut(storage_id, SparseSet::<T>::new)
+            .reserve(capacity);
+
+        is_new
+    }
+
+    /// Creates the storage of `T` if it doesn't exist yet and enables all tracking for it.\
+    /// Returns `true` if the storage was created.
+    pub fn register_tracked<T: Send + Sync + Component>(&mut self) -> bool {
+        let is_new = self.register::<T>();
+
+        self.track_all::<T>();
+
+        is_new
+    }
+
+    /// Sets the callback called when a `T` component is inserted, see [`SparseSet::on_insertion`].\
+    /// Creates the storage if it doesn't exist yet.
+    pub fn on_insertion<T: Send + Sync + Component>(
+        &mut self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .on_insertion(f);
+    }
+
+    /// Sets the callback called when a `T` component is removed or deleted, see [`SparseSet::on_removal`].\
+    /// Creates the storage if it doesn't exist yet.
+    pub fn on_removal<T: Send + Sync + Component>(
+        &mut self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .on_removal(f);
+    }
+
+    /// Sets a callback called with the id and name of each storage created after this call.\
+    /// It is called once per storage and isn't called for [`Unique`] storages.
+    ///
+    /// The callback is called while the storages are locked, it must not access the `World`.
+    pub fn on_storage_registered(
+        &mut self,
+        f: impl Fn(StorageId, &'static str) + Send + Sync + 'static,
+    ) {
+        self.on_storage_registered = Some(Box::new(f));
+    }
+
     #[doc = "Retrieve components of `entity`.
 
 Multiple components can be queried at the same time using a tuple.
@@ -1441,6 +2007,55 @@ for (i, j) in &mut iter {
             .is_alive(entity)
     }
 
+    /// Returns true if entity matches a living entity.\
+    /// Unlike [`AllStorages::is_entity_alive`], only requires a shared reference.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn is_alive(&self, entity: EntityId) -> bool {
+        self.borrow::<EntitiesView<'_>>().unwrap().is_alive(entity)
+    }
+
+    /// Attaches a debug name to `entity`, replacing the previous one.\
+    /// Names are only used by [`EntityId::debug_named`] and [`MissingComponent::debug_named`](error::MissingComponent::debug_named), they don't affect anything else.
+    ///
+    /// The name is dropped when the entity is deleted with [`AllStorages::delete_entity`], [`AllStorages::retain_entities`] or [`AllStorages::clear`].
+    /// Entities deleted by other means keep their name in memory until [`AllStorages::remove_entity_name`] is called.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let player = all_storages.add_entity(());
+    /// all_storages.set_entity_name(player, "Player");
+    ///
+    /// assert_eq!(all_storages.entity_name(player), Some("Player"));
+    /// assert_eq!(format!("{:?}", player.debug_named(&all_storages)), "Player (EId(0.0))");
+    /// ```
+    pub fn set_entity_name(&mut self, entity: EntityId, name: impl Into<String>) {
+        self.entity_names.insert(entity, name.into());
+    }
+
+    /// Removes the debug name of `entity` and returns it.
+    pub fn remove_entity_name(&mut self, entity: EntityId) -> Option<String> {
+        self.entity_names.remove(&entity)
+    }
+
+    /// Returns the debug name of `entity` if it has one.
+    pub fn entity_name(&self, entity: EntityId) -> Option<&str> {
+        self.entity_names.get(&entity).map(String::as_str)
+    }
+
     /// Moves an entity from a `World` to another.
     ///
     /// ```
@@ -1565,6 +2180,246 @@ for (i, j) in &mut iter {
             );
         }
     }
+
+    /// Moves `ids` and all their components to another `World`.\
+    /// Entities get new ids in `other`, the returned `Vec` maps the old ids to the new ones, in `ids` order.\
+    /// Dead entities are ignored.
+    ///
+    /// Storages missing in `other` are created, including `!Send` and `!Sync` ones.
+    /// Debug names follow their entity.
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages2.add_entity(());
+    /// let entity0 = all_storages1.add_entity(USIZE(0));
+    /// let entity1 = all_storages1.add_entity(USIZE(1));
+    ///
+    /// let migrated = all_storages1.migrate_entities(&mut all_storages2, &[entity0, entity1]);
+    ///
+    /// assert_eq!(migrated.len(), 2);
+    /// assert!(!all_storages1.is_entity_alive(entity0));
+    /// assert_eq!(all_storages2.get::<&USIZE>(migrated[1].1).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[track_caller]
+    pub fn migrate_entities(
+        &mut self,
+        other: &mut AllStorages,
+        ids: &[EntityId],
+    ) -> Vec<(EntityId, EntityId)> {
+        let current = self.get_current();
+        let other_current = other.get_current();
+
+        let entities = self.exclusive_storage_mut::<Entities>().unwrap();
+        let other_entities = other.exclusive_storage_mut::<Entities>().unwrap();
+
+        let migrated = ids
+            .iter()
+            .filter(|&&entity| entities.delete_unchecked(entity))
+            .map(|&entity| (entity, other_entities.generate()))
+            .collect::<Vec<_>>();
+
+        for &(from, to) in &migrated {
+            if let Some(name) = self.entity_names.remove(&from) {
+                other.entity_names.insert(to, name);
+            }
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            let storage = unsafe { &mut *storage.0 }.get_mut();
+
+            for &(from, to) in &migrated {
+                storage.move_component_from(other, from, to, current, other_current);
+            }
+        }
+
+        migrated
+    }
+
+    /// Copies `ids` and their components to a new `World`.\
+    /// Entities get new ids in the new `World`, [`ExtractedSubset::remap`] can be used to update components referencing other entities.
+    /// [`extract_subset_with_remap`](AllStorages::extract_subset_with_remap) does it as part of the extraction.
+    ///
+    /// Only components with [`Component::CLONE`] set are copied, the name of the storages with components that couldn't be copied are listed in [`ExtractedSubset::skipped`].\
+    /// Dead entities are ignored.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, EntityId, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// #[shipyard(clone)]
+    /// struct Parent(EntityId);
+    ///
+    /// #[derive(Component)]
+    /// struct Name(&'static str);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let parent = all_storages.add_entity(Name("parent"));
+    /// let child = all_storages.add_entity(Parent(parent));
+    ///
+    /// let subset = all_storages.extract_subset(&[parent, child]).unwrap();
+    ///
+    /// assert_eq!(subset.skipped.len(), 1);
+    ///
+    /// let new_child = subset.remap(child).unwrap();
+    /// let new_parent = subset.remap(parent).unwrap();
+    ///
+    /// subset.world.run(|mut parents: ViewMut<Parent>| {
+    ///     for parent in (&mut parents).iter() {
+    ///         parent.0 = subset.remap(parent.0).unwrap();
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(subset.world.get::<&Parent>(new_child).unwrap().0, new_parent);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn extract_subset(&self, ids: &[EntityId]) -> Result<ExtractedSubset, error::GetStorage> {
+        self.extract_subset_with_remap(ids, |_, _| {})
+    }
+    /// Same as [`extract_subset`](AllStorages::extract_subset) but calls `remap` once all components are copied.\
+    /// `remap` receives the new `World`'s [`AllStorages`] and a function returning the new id of an entity from the original `World`.
+    /// It can be used to update components referencing other entities.
+    ///
+    /// ### Borrows
+    ///
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, EntityId, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// #[shipyard(clone)]
+    /// struct Parent(EntityId);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let parent = all_storages.add_entity(());
+    /// let child = all_storages.add_entity(Parent(parent));
+    ///
+    /// let subset = all_storages
+    ///     .extract_subset_with_remap(&[parent, child], |all_storages, remap| {
+    ///         let mut parents = all_storages.borrow::<ViewMut<Parent>>().unwrap();
+    ///
+    ///         for parent in (&mut parents).iter() {
+    ///             parent.0 = remap(parent.0).unwrap();
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let new_child = subset.remap(child).unwrap();
+    /// let new_parent = subset.remap(parent).unwrap();
+    ///
+    /// assert_eq!(subset.world.get::<&Parent>(new_child).unwrap().0, new_parent);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn extract_subset_with_remap<F>(
+        &self,
+        ids: &[EntityId],
+        remap: F,
+    ) -> Result<ExtractedSubset, error::GetStorage>
+    where
+        F: FnOnce(&mut AllStorages, &dyn Fn(EntityId) -> Option<EntityId>),
+    {
+        let mut world = World::new();
+        let other = world.all_storages.get_mut();
+        let other_current = other.get_current();
+
+        let mut mapping = Vec::with_capacity(ids.len());
+        {
+            let entities = self.entities()?;
+            let other_entities = other.exclusive_storage_mut::<Entities>().unwrap();
+            let mut seen = crate::ShipHashSet::default();
+
+            for &id in ids {
+                if entities.is_alive(id) && seen.insert(id) {
+                    mapping.push((id, other_entities.generate()));
+                }
+            }
+        }
+
+        let mut skipped = Vec::new();
+        let storages = self.storages.read();
+
+        for (&storage_id, storage) in storages.iter() {
+            let storage = unsafe { &*storage.0 }.borrow().map_err(|err| {
+                error::GetStorage::StorageBorrow {
+                    name: None,
+                    id: storage_id,
+                    borrow: err,
+                }
+            })?;
+
+            let mut cloned_all = true;
+            for &(from, to) in &mapping {
+                cloned_all &= storage.clone_component_to(other, from, to, other_current);
+            }
+
+            if !cloned_all {
+                skipped.push(storage.name());
+            }
+        }
+
+        drop(storages);
+
+        let lookup: ShipHashMap<EntityId, EntityId> = mapping.iter().copied().collect();
+
+        remap(world.all_storages.get_mut(), &|entity| {
+            lookup.get(&entity).copied()
+        });
+
+        Ok(ExtractedSubset {
+            world,
+            ids: mapping,
+            lookup,
+            skipped,
+        })
+    }
+}
+
+/// [`AllStorages::extract_subset`]'s return value.
+#[cfg(feature = "std")]
+pub struct ExtractedSubset {
+    /// `World` containing the copied entities.
+    pub world: World,
+    /// Original and new id of each copied entity.
+    pub ids: Vec<(EntityId, EntityId)>,
+    /// Name of the storages that had components of copied entities but couldn't clone them.
+    pub skipped: Vec<Cow<'static, str>>,
+    lookup: ShipHashMap<EntityId, EntityId>,
+}
+
+#[cfg(feature = "std")]
+impl ExtractedSubset {
+    /// Returns the id in the new `World` of an entity from the original `World`.
+    pub fn remap(&self, entity: EntityId) -> Option<EntityId> {
+        self.lookup.get(&entity).copied()
+    }
 }
 
 impl core::fmt::Debug for AllStorages {
@@ -0,0 +1,66 @@
+use crate::ShipBuildHasher;
+use core::hash::{BuildHasher, Hasher};
+use siphasher::sip::SipHasher13;
+
+/// Hasher used by [`AllStorages`](crate::AllStorages) to find its storages.
+///
+/// Without seed it uses the default hasher.\
+/// With a seed it uses `SipHasher13` keyed with the seed, the same seed always results in the same hashes.
+#[derive(Clone, Default)]
+pub(crate) struct SeededHasher {
+    seed: Option<u64>,
+    default: ShipBuildHasher,
+}
+
+impl SeededHasher {
+    pub(crate) fn new(seed: Option<u64>) -> SeededHasher {
+        SeededHasher {
+            seed,
+            default: ShipBuildHasher::default(),
+        }
+    }
+}
+
+impl BuildHasher for SeededHasher {
+    type Hasher = SeededHasherState;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        match self.seed {
+            Some(seed) => SeededHasherState::Keyed(SipHasher13::new_with_keys(seed, 0)),
+            None => SeededHasherState::Default(self.default.build_hasher()),
+        }
+    }
+}
+
+/// [`Hasher`] built by [`SeededHasher`].
+pub(crate) enum SeededHasherState {
+    Default(<ShipBuildHasher as BuildHasher>::Hasher),
+    Keyed(SipHasher13),
+}
+
+impl Hasher for SeededHasherState {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self {
+            SeededHasherState::Default(hasher) => hasher.finish(),
+            SeededHasherState::Keyed(hasher) => hasher.finish(),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        match self {
+            SeededHasherState::Default(hasher) => hasher.write(bytes),
+            SeededHasherState::Keyed(hasher) => hasher.write(bytes),
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        match self {
+            SeededHasherState::Default(hasher) => hasher.write_u64(i),
+            SeededHasherState::Keyed(hasher) => hasher.write_u64(i),
+        }
+    }
+}
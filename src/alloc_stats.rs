@@ -0,0 +1,89 @@
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::Cell;
+use std::alloc::System;
+
+std::thread_local! {
+    static THREAD_STATS: Cell<AllocStats> = const {
+        Cell::new(AllocStats {
+            allocations: 0,
+            bytes: 0,
+        })
+    };
+}
+
+/// Number of allocations and allocated bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of calls to `alloc`, `alloc_zeroed` and `realloc`.
+    pub allocations: u64,
+    /// Sum of the requested sizes, for `realloc` the new size is counted.
+    pub bytes: u64,
+}
+
+impl AllocStats {
+    /// Returns the stats of the current thread since its creation.
+    pub(crate) fn current_thread() -> AllocStats {
+        THREAD_STATS.try_with(Cell::get).unwrap_or_default()
+    }
+    /// Returns the stats accumulated between `start` and `self`.
+    pub(crate) fn since(self, start: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations - start.allocations,
+            bytes: self.bytes - start.bytes,
+        }
+    }
+}
+
+/// Global allocator counting allocations made by each thread.
+///
+/// It has to be installed for [`World::last_run_alloc_stats`](crate::World::last_run_alloc_stats) to report anything.
+///
+/// ### Example
+/// ```
+/// use shipyard::alloc_stats::CountingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator(std::alloc::System);
+/// ```
+pub struct CountingAllocator<A = System>(pub A);
+
+impl<A> CountingAllocator<A> {
+    #[inline]
+    fn count(size: usize) {
+        // The thread local can be gone during thread destruction, these allocations are not counted
+        let _ = THREAD_STATS.try_with(|stats| {
+            let mut current = stats.get();
+            current.allocations += 1;
+            current.bytes += size as u64;
+            stats.set(current);
+        });
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+
+        self.0.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::count(layout.size());
+
+        self.0.alloc_zeroed(layout)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.0.dealloc(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count(new_size);
+
+        self.0.realloc(ptr, layout, new_size)
+    }
+}
@@ -20,6 +20,7 @@ pub struct AtomicRefCell<T: ?Sized> {
     send: Option<u64>,
     #[cfg(feature = "thread_local")]
     is_sync: bool,
+    frozen: bool,
     _non_send_sync: PhantomData<*const ()>,
     inner: UnsafeCell<T>,
 }
@@ -43,6 +44,7 @@ impl<T: Send + Sync> AtomicRefCell<T> {
             send: None,
             #[cfg(feature = "thread_local")]
             is_sync: true,
+            frozen: false,
             _non_send_sync: PhantomData,
             inner: UnsafeCell::new(value),
         }
@@ -61,6 +63,7 @@ impl<T: Sync> AtomicRefCell<T> {
             thread_id,
             send,
             is_sync: true,
+            frozen: false,
             _non_send_sync: PhantomData,
             inner: UnsafeCell::new(value),
         }
@@ -77,6 +80,7 @@ impl<T: Send> AtomicRefCell<T> {
             thread_id: Arc::new(|| unreachable!()),
             send: None,
             is_sync: false,
+            frozen: false,
             _non_send_sync: PhantomData,
             inner: UnsafeCell::new(value),
         }
@@ -98,6 +102,7 @@ impl<T> AtomicRefCell<T> {
             thread_id,
             send,
             is_sync: false,
+            frozen: false,
             _non_send_sync: PhantomData,
             inner: UnsafeCell::new(value),
         }
@@ -196,6 +201,10 @@ impl<T: ?Sized> AtomicRefCell<T> {
             }
         }
 
+        if self.frozen {
+            return Err(error::Borrow::Frozen);
+        }
+
         match self.borrow_state.write() {
             Ok(borrow) => {
                 Ok(ARefMut {
@@ -223,6 +232,18 @@ impl<T: ?Sized> AtomicRefCell<T> {
 
         self.inner.get_mut()
     }
+    /// Copies `other`'s `Send` and `Sync` constraints.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn copy_thread_constraints<U: ?Sized>(&mut self, other: &AtomicRefCell<U>) {
+        self.send = other.send;
+        self.is_sync = other.is_sync;
+    }
+    /// Makes all subsequent exclusive borrows fail with [`error::Borrow::Frozen`] until unfrozen.
+    #[inline]
+    pub(crate) fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
 }
 
 /// Wraps an `AtomicRefcell`'s shared borrow.
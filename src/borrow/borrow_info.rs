@@ -19,6 +19,7 @@ use crate::views::{
     AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
     View, ViewMut,
 };
+use alloc::format;
 use alloc::vec::Vec;
 use core::any::type_name;
 
@@ -57,11 +58,65 @@ use core::any::type_name;
 ///     ) {}
 /// }
 /// ```
+///
+/// ### Example using `custom_borrow_info`:
+/// ```rust
+/// use shipyard::{
+///     borrow::{BorrowInfo, Mutability}, scheduler::info::TypeInfo, sparse_set::SparseSet, track,
+///     StorageId, View,
+/// };
+///
+/// # struct Position {}
+/// # impl shipyard::Component for Position {
+/// #     type Tracking = track::Untracked;
+/// # }
+/// #
+/// struct SpatialQuery<'v> {
+///     positions: View<'v, Position>,
+/// }
+///
+/// // SAFE: The only storage borrowed is declared.
+/// unsafe impl BorrowInfo for SpatialQuery<'_> {
+///     fn borrow_info(info: &mut Vec<TypeInfo>) {
+///         Self::extend_custom_borrow_info(info);
+///     }
+///     fn custom_borrow_info() -> Vec<(StorageId, Mutability)> {
+///         vec![(StorageId::of::<SparseSet<Position>>(), Mutability::Shared)]
+///     }
+///     fn enable_tracking(
+///         _: &mut Vec<
+///             for<'a> fn(&'a shipyard::AllStorages) -> Result<(), shipyard::error::GetStorage>,
+///         >,
+///     ) {}
+/// }
+/// ```
 pub unsafe trait BorrowInfo {
     /// This information is used during workload creation to determine which systems can run in parallel.
     ///
     /// A borrow error might happen if the information is not correct.
     fn borrow_info(info: &mut Vec<TypeInfo>);
+    /// Simpler way to list the storages borrowed by custom views and how they are borrowed.\
+    /// [`borrow_info`](BorrowInfo::borrow_info) can forward to it with [`extend_custom_borrow_info`](BorrowInfo::extend_custom_borrow_info).
+    ///
+    /// The storages listed are assumed to be `Send` and `Sync`.
+    ///
+    /// Declaring less storages than what is actually borrowed or a shared access where the view borrows exclusively is unsound.
+    fn custom_borrow_info() -> Vec<(StorageId, Mutability)> {
+        Vec::new()
+    }
+    /// Adds the storages listed by [`custom_borrow_info`](BorrowInfo::custom_borrow_info) to `info`.
+    fn extend_custom_borrow_info(info: &mut Vec<TypeInfo>) {
+        info.extend(
+            Self::custom_borrow_info()
+                .into_iter()
+                .map(|(storage_id, mutability)| TypeInfo {
+                    name: format!("{:?}", storage_id).into(),
+                    mutability,
+                    storage_id,
+                    thread_safe: true,
+                }),
+        );
+    }
     /// Enable tracking on the `World` where this storage is borrowed.
     #[allow(clippy::type_complexity)]
     fn enable_tracking(
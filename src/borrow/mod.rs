@@ -21,6 +21,7 @@ use crate::atomic_refcell::{ARef, ARefMut, SharedBorrow};
 use crate::component::{Component, Unique};
 use crate::error;
 use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
 use crate::system::Nothing;
 use crate::tracking::{Tracking, TrackingTimestamp};
 use crate::unique::UniqueStorage;
@@ -87,6 +88,23 @@ pub trait Borrow {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::View<'a>, error::GetStorage>;
+
+    /// Used by the `Option<T>` implementation.\
+    /// Returns `None` when a storage doesn't exist instead of creating it or returning [`error::GetStorage::MissingStorage`].\
+    /// Other errors are propagated.
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        match Self::borrow(all_storages, all_borrow, last_run, current) {
+            Ok(view) => Ok(Some(view)),
+            Err(error::GetStorage::MissingStorage { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 // this is needed for downstream crate to impl System
@@ -185,6 +203,20 @@ where
 
         Ok(View::new(sparse_set, borrow, all_borrow, last_run, current))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<SparseSet<T>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -211,6 +243,20 @@ where
             sparse_set, borrow, all_borrow, last_run, current,
         )))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSend<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -237,6 +283,20 @@ where
             sparse_set, borrow, all_borrow, last_run, current,
         )))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSync<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -264,6 +324,20 @@ where
             sparse_set, borrow, all_borrow, last_run, current,
         )))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSendSync<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<T: Send + Sync + Component, Track> Borrow for ViewMut<'_, T, Track>
@@ -296,6 +370,20 @@ where
             phantom: PhantomData,
         })
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<SparseSet<T>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -330,6 +418,20 @@ where
             phantom: PhantomData,
         }))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSend<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -364,6 +466,20 @@ where
             phantom: PhantomData,
         }))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSync<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -398,6 +514,20 @@ where
             phantom: PhantomData,
         }))
     }
+
+    #[inline]
+    fn borrow_if_present<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+        if all_storages.contains_storage(StorageId::of::<NonSendSync<SparseSet<T>>>()) {
+            Self::borrow(all_storages, all_borrow, last_run, current).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<T: Send + Sync + Unique> Borrow for UniqueView<'_, T> {
@@ -616,7 +746,7 @@ impl<T: Borrow> Borrow for Option<T> {
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
     ) -> Result<Self::View<'a>, error::GetStorage> {
-        Ok(T::borrow(all_storages, all_borrow, last_run, current).ok())
+        T::borrow_if_present(all_storages, all_borrow, last_run, current)
     }
 }
 
@@ -634,6 +764,21 @@ macro_rules! impl_borrow {
             ) -> Result<Self::View<'a>, error::GetStorage> {
                 Ok(($($type::borrow(all_storages, all_borrow.clone(), last_run, current)?,)+))
             }
+
+            #[inline]
+            fn borrow_if_present<'a>(
+                all_storages: &'a AllStorages,
+                all_borrow: Option<SharedBorrow<'a>>,
+                last_run: Option<TrackingTimestamp>,
+                current: TrackingTimestamp
+            ) -> Result<Option<Self::View<'a>>, error::GetStorage> {
+                Ok(Some(($(
+                    match $type::borrow_if_present(all_storages, all_borrow.clone(), last_run, current)? {
+                        Some(view) => view,
+                        None => return Ok(None),
+                    },
+                )+)))
+            }
         }
     }
 }
@@ -0,0 +1,203 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::Unique;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::scheduler::info::TypeInfo;
+use crate::sparse_set::{TupleAddComponent, TupleRemove};
+use crate::tracking::TrackingTimestamp;
+use crate::views::UniqueView;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+type Command = Box<dyn FnOnce(&mut AllStorages) + Send>;
+
+/// Queue of structural modifications, stored as a unique.
+pub(crate) struct CommandBuffer {
+    commands: Mutex<Vec<Command>>,
+}
+
+impl Unique for CommandBuffer {}
+
+impl Default for CommandBuffer {
+    fn default() -> Self {
+        CommandBuffer {
+            commands: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl CommandBuffer {
+    fn push(&self, command: Command) {
+        self.commands.lock().unwrap().push(command);
+    }
+    fn take(&self) -> Vec<Command> {
+        core::mem::take(&mut *self.commands.lock().unwrap())
+    }
+    fn is_empty(&self) -> bool {
+        self.commands.lock().unwrap().is_empty()
+    }
+}
+
+impl AllStorages {
+    /// Applies all commands queued with [`CommandBufferViewMut`], in the order they were queued.
+    ///
+    /// Commands are applied automatically at the end of each workload run, even if a system returned an error.
+    /// Calling this method from a system taking [`AllStoragesViewMut`] applies them mid-workload.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, CommandBufferViewMut, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut commands: CommandBufferViewMut| {
+    ///     commands.spawn((Health(10),));
+    /// });
+    ///
+    /// world.run(|mut all_storages: AllStoragesViewMut| all_storages.apply_commands());
+    ///
+    /// world.run(|healths: View<Health>| assert_eq!(healths.len(), 1));
+    /// ```
+    ///
+    /// [`AllStoragesViewMut`]: crate::AllStoragesViewMut
+    pub fn apply_commands(&mut self) {
+        loop {
+            let commands = match self.borrow::<UniqueView<'_, CommandBuffer>>() {
+                Ok(buffer) => buffer.take(),
+                Err(_) => return,
+            };
+
+            if commands.is_empty() {
+                return;
+            }
+
+            for command in commands {
+                command(self);
+            }
+        }
+    }
+    /// Returns `true` if commands are waiting to be applied.
+    pub(crate) fn has_pending_commands(&self) -> bool {
+        match self.borrow::<UniqueView<'_, CommandBuffer>>() {
+            Ok(buffer) => !buffer.is_empty(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Queues structural modifications to apply later.
+///
+/// Multiple systems can borrow it at the same time, even when running in parallel.\
+/// The commands are applied in the order they were queued at the end of the workload run,
+/// or when [`AllStorages::apply_commands`] is called.\
+/// When a system returns an error, the commands queued by the systems that ran are still applied.
+///
+/// ### Example
+/// ```
+/// use shipyard::{CommandBufferViewMut, Component, IntoIter, View, Workload, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn cleanup(healths: View<Health>, mut commands: CommandBufferViewMut) {
+///     for (entity, health) in healths.iter().with_id() {
+///         if health.0 == 0 {
+///             commands.despawn(entity);
+///         }
+///     }
+/// }
+///
+/// let mut world = World::new();
+///
+/// let dead = world.add_entity(Health(0));
+/// let alive = world.add_entity(Health(10));
+///
+/// Workload::new("cleanup")
+///     .with_system(cleanup)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_default_workload().unwrap();
+///
+/// assert!(!world.is_entity_alive(dead));
+/// assert!(world.is_entity_alive(alive));
+/// ```
+pub struct CommandBufferViewMut<'v> {
+    buffer: UniqueView<'v, CommandBuffer>,
+}
+
+impl CommandBufferViewMut<'_> {
+    /// Queues a custom command.
+    pub fn push<F: FnOnce(&mut AllStorages) + Send + 'static>(&mut self, command: F) {
+        self.buffer.push(Box::new(command));
+    }
+    /// Queues the creation of a new entity with `components`.
+    pub fn spawn<C: TupleAddComponent + Send + 'static>(&mut self, components: C) {
+        self.push(move |all_storages| {
+            all_storages.add_entity(components);
+        });
+    }
+    /// Queues the deletion of `entity` and all its components.
+    pub fn despawn(&mut self, entity: EntityId) {
+        self.push(move |all_storages| {
+            all_storages.delete_entity(entity);
+        });
+    }
+    /// Queues the addition of `components` to `entity`.\
+    /// Does nothing if `entity` is dead when the command is applied.
+    pub fn add_component<C: TupleAddComponent + Send + 'static>(
+        &mut self,
+        entity: EntityId,
+        components: C,
+    ) {
+        self.push(move |all_storages| {
+            if all_storages.is_entity_alive(entity) {
+                all_storages.add_component(entity, components);
+            }
+        });
+    }
+    /// Queues the removal of `C` components from `entity`, the components are dropped.
+    pub fn remove_component<C: TupleRemove + 'static>(&mut self, entity: EntityId) {
+        self.push(move |all_storages| {
+            all_storages.remove::<C>(entity);
+        });
+    }
+}
+
+impl Borrow for CommandBufferViewMut<'_> {
+    type View<'a> = CommandBufferViewMut<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        match all_storages.borrow::<UniqueView<'_, CommandBuffer>>() {
+            Ok(_) => {}
+            Err(error::GetStorage::MissingStorage { .. }) => {
+                all_storages.add_unique(CommandBuffer::default())
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(CommandBufferViewMut {
+            buffer: UniqueView::borrow(all_storages, all_borrow, last_run, current)?,
+        })
+    }
+}
+
+unsafe impl BorrowInfo for CommandBufferViewMut<'_> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        UniqueView::<CommandBuffer>::borrow_info(info);
+    }
+
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
@@ -1,21 +1,47 @@
-use crate::tracking::Tracking;
+use crate::tracking::{ModificationCompare, Tracking};
 
 /// Indicates that a `struct` or `enum` can be store in the `World`.
 #[cfg(feature = "thread_local")]
 pub trait Component: Sized + 'static {
     /// Kind of event to track for this component.
     type Tracking: Tracking;
+    /// When set, components are only flagged *modified* if their value changed when the [`Mut`](crate::Mut) is dropped.
+    ///
+    /// Requires modification tracking, set it with `Some(ModificationCompare::new())` or derive it with `#[shipyard(compare)]` for components implementing `Clone` and `PartialEq`.
+    const MODIFICATION_COMPARE: Option<ModificationCompare<Self>> = None;
+    /// When set, [`AllStorages::extract_subset`](crate::AllStorages::extract_subset) and [`World::try_clone`](crate::World::try_clone) can copy this component to another `World`.
+    ///
+    /// Components implementing `Clone` can set it to `Some(Self::clone)` or derive it with `#[shipyard(clone)]`.
+    const CLONE: Option<fn(&Self) -> Self> = None;
 }
 /// Indicates that a `struct` or `enum` can be store in the `World`.
 #[cfg(not(feature = "thread_local"))]
 pub trait Component: Sized + Send + Sync + 'static {
     /// Kind of event to track for this component.
     type Tracking: Tracking;
+    /// When set, components are only flagged *modified* if their value changed when the [`Mut`](crate::Mut) is dropped.
+    ///
+    /// Requires modification tracking, set it with `Some(ModificationCompare::new())` or derive it with `#[shipyard(compare)]` for components implementing `Clone` and `PartialEq`.
+    const MODIFICATION_COMPARE: Option<ModificationCompare<Self>> = None;
+    /// When set, [`AllStorages::extract_subset`](crate::AllStorages::extract_subset) and [`World::try_clone`](crate::World::try_clone) can copy this component to another `World`.
+    ///
+    /// Components implementing `Clone` can set it to `Some(Self::clone)` or derive it with `#[shipyard(clone)]`.
+    const CLONE: Option<fn(&Self) -> Self> = None;
 }
 
 /// Indicates that a `struct` or `enum` can be store a single time in the `World`.
 #[cfg(feature = "thread_local")]
-pub trait Unique: Sized + 'static {}
+pub trait Unique: Sized + 'static {
+    /// When set, [`World::try_clone`](crate::World::try_clone) can copy this unique.
+    ///
+    /// Uniques implementing `Clone` can set it to `Some(Self::clone)` or derive it with `#[shipyard(clone)]`.
+    const CLONE: Option<fn(&Self) -> Self> = None;
+}
 /// Indicates that a `struct` or `enum` can be store a single time in the `World`.
 #[cfg(not(feature = "thread_local"))]
-pub trait Unique: Sized + Send + Sync + 'static {}
+pub trait Unique: Sized + Send + Sync + 'static {
+    /// When set, [`World::try_clone`](crate::World::try_clone) can copy this unique.
+    ///
+    /// Uniques implementing `Clone` can set it to `Some(Self::clone)` or derive it with `#[shipyard(clone)]`.
+    const CLONE: Option<fn(&Self) -> Self> = None;
+}
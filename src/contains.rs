@@ -1,6 +1,6 @@
 use crate::component::Component;
 use crate::entity_id::EntityId;
-use crate::sparse_set::SparseSet;
+use crate::sparse_set::{SparseArray, SparseSet, BUCKET_SIZE};
 use crate::tracking::Tracking;
 use crate::views::{View, ViewMut};
 
@@ -28,6 +28,80 @@ impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains for &'b mut ViewMut<'a,
     }
 }
 
+/// Sparse arrays of one or multiple views, captured to check entities repeatedly.\
+/// Created with [`IntoContainsChecker::checker`].
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoContainsChecker, View, World};
+///
+/// #[derive(Component)]
+/// struct Position;
+///
+/// #[derive(Component)]
+/// struct Collider;
+///
+/// let mut world = World::new();
+///
+/// let entity0 = world.add_entity((Position, Collider));
+/// let entity1 = world.add_entity(Position);
+///
+/// world.run(|positions: View<Position>, colliders: View<Collider>| {
+///     let checker = (&positions, &colliders).checker();
+///
+///     assert!(checker.contains(entity0));
+///     assert!(!checker.contains(entity1));
+/// });
+/// ```
+pub struct ContainsChecker<'a, const N: usize>([&'a SparseArray<EntityId, BUCKET_SIZE>; N]);
+
+impl<const N: usize> ContainsChecker<'_, N> {
+    /// Returns true if all captured storages contains `entity`.\
+    /// Returns the same result as [`Contains::contains`] on the views.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.0.iter().all(|sparse| sparse.contains(entity))
+    }
+}
+
+/// Creates a [`ContainsChecker`] from one or multiple views.
+pub trait IntoContainsChecker {
+    /// Checker returned by [`IntoContainsChecker::checker`].
+    type Checker;
+
+    /// Captures the views' sparse arrays.
+    fn checker(self) -> Self::Checker;
+}
+
+impl<'a, T: Component, Track: Tracking> IntoContainsChecker for &'a View<'_, T, Track> {
+    type Checker = ContainsChecker<'a, 1>;
+
+    #[inline]
+    fn checker(self) -> Self::Checker {
+        ContainsChecker([&self.sparse])
+    }
+}
+
+impl<'a, T: Component, Track: Tracking> IntoContainsChecker for &'a ViewMut<'_, T, Track> {
+    type Checker = ContainsChecker<'a, 1>;
+
+    #[inline]
+    fn checker(self) -> Self::Checker {
+        ContainsChecker([&self.sparse])
+    }
+}
+
+impl<'a, T: Component, Track: Tracking> IntoContainsChecker for &'a mut ViewMut<'_, T, Track> {
+    type Checker = ContainsChecker<'a, 1>;
+
+    #[inline]
+    fn checker(self) -> Self::Checker {
+        let view: &'a ViewMut<'_, T, Track> = self;
+
+        ContainsChecker([&view.sparse])
+    }
+}
+
 macro_rules! impl_contains {
     ($(($type: ident, $index: tt))+) => {
         impl<$($type: Contains),+> Contains for ($($type,)+) {
@@ -35,6 +109,15 @@ macro_rules! impl_contains {
                 $(self.$index.contains(entity))&&+
             }
         }
+
+        impl<'a, $($type: IntoContainsChecker<Checker = ContainsChecker<'a, 1>>),+> IntoContainsChecker for ($($type,)+) {
+            type Checker = ContainsChecker<'a, { [$($index),+].len() }>;
+
+            #[inline]
+            fn checker(self) -> Self::Checker {
+                ContainsChecker([$(self.$index.checker().0[0]),+])
+            }
+        }
     }
 }
 
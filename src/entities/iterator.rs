@@ -4,28 +4,20 @@ use crate::entity_id::EntityId;
 /// Iterator over [`Entities`].
 ///
 /// [`Entities`]: crate::entities::Entities
-#[allow(clippy::type_complexity)]
-pub struct EntitiesIter<'a>(
-    core::iter::FilterMap<
-        core::iter::Enumerate<core::slice::Iter<'a, EntityId>>,
-        fn((usize, &EntityId)) -> Option<EntityId>,
-    >,
-);
+pub struct EntitiesIter<'a> {
+    pub(super) data: &'a [EntityId],
+    pub(super) indices: core::ops::Range<usize>,
+}
 
 impl<'a> IntoIterator for &'a Entities {
     type Item = EntityId;
     type IntoIter = EntitiesIter<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
-        EntitiesIter(self.data.iter().enumerate().filter_map(filter_map))
-    }
-}
-
-fn filter_map((i, &entity): (usize, &EntityId)) -> Option<EntityId> {
-    if i == entity.uindex() {
-        Some(entity)
-    } else {
-        None
+        EntitiesIter {
+            data: &self.data,
+            indices: 0..self.data.len(),
+        }
     }
 }
 
@@ -33,9 +25,18 @@ impl<'a> Iterator for EntitiesIter<'a> {
     type Item = EntityId;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        for index in &mut self.indices {
+            // SAFE indices are in bound
+            let entity = *unsafe { self.data.get_unchecked(index) };
+
+            if index == entity.uindex() {
+                return Some(entity);
+            }
+        }
+
+        None
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        (0, Some(self.indices.len()))
     }
 }
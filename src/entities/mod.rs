@@ -5,11 +5,12 @@ pub use iterator::EntitiesIter;
 use crate::add_component::AddComponent;
 use crate::add_distinct_component::AddDistinctComponent;
 use crate::add_entity::AddEntity;
+use crate::all_storages::AllStorages;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::memory_usage::StorageMemoryUsage;
 use crate::reserve::{BulkEntityIter, BulkReserve};
-use crate::storage::Storage;
+use crate::storage::{Storage, StorageId};
 use crate::tracking::TrackingTimestamp;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -39,6 +40,9 @@ pub struct Entities {
     pub(crate) data: Vec<EntityId>,
     list: Option<(usize, usize)>,
     on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
+    /// World tag given to generated entities.
+    #[cfg(feature = "entity_tag")]
+    tag: u8,
 }
 
 impl Entities {
@@ -48,6 +52,16 @@ impl Entities {
             data: Vec::new(),
             list: None,
             on_deletion: None,
+            #[cfg(feature = "entity_tag")]
+            tag: 0,
+        }
+    }
+    #[cfg(feature = "entity_tag")]
+    #[inline]
+    pub(crate) fn new_tagged(tag: u8) -> Self {
+        Entities {
+            tag,
+            ..Entities::new()
         }
     }
     /// Returns `true` if `entity` matches a living entity.
@@ -153,17 +167,40 @@ impl Entities {
                 *self.data.get_unchecked(old_index)
             }
         } else {
-            let entity_id = EntityId::new(self.data.len() as u64);
-            self.data.push(entity_id);
-            entity_id
+            self.generate_new()
         }
     }
+    /// Generates an id at the end of `data`, never reusing a dead slot.
+    ///
+    /// Bulk operations rely on it to return a contiguous slice of new ids.
+    pub(crate) fn generate_new(&mut self) -> EntityId {
+        let entity_id = self.new_id(self.data.len() as u64);
+        self.data.push(entity_id);
+        entity_id
+    }
     pub(crate) fn bulk_generate(&mut self, count: usize) -> &[EntityId] {
-        self.data
-            .extend((self.data.len() as u64..(self.data.len() + count) as u64).map(EntityId::new));
+        let start = self.data.len() as u64;
+
+        self.data.reserve(count);
+        for index in start..start + count as u64 {
+            let entity_id = self.new_id(index);
+            self.data.push(entity_id);
+        }
 
         &self.data[self.data.len() - count..self.data.len()]
     }
+    /// Makes the id of a new entity at `index`, tagged with this `World`'s tag.
+    #[cfg(feature = "entity_tag")]
+    #[inline]
+    fn new_id(&self, index: u64) -> EntityId {
+        EntityId::new(index).with_world_tag(self.tag)
+    }
+    /// Makes the id of a new entity at `index`.
+    #[cfg(not(feature = "entity_tag"))]
+    #[inline]
+    fn new_id(&self, index: u64) -> EntityId {
+        EntityId::new(index)
+    }
     /// Deletes an entity, returns true if the entity was alive.  
     /// If the entity has components, they will not be deleted and still be accessible using this id.
     pub fn delete_unchecked(&mut self, entity_id: EntityId) -> bool {
@@ -243,7 +280,9 @@ impl Entities {
         entity_id
     }
     /// Creates multiple new entities and returns an iterator yielding the new [`EntityId`]s.  
-    /// Multiple components can be added at the same time using a tuple.
+    /// Multiple components can be added at the same time using a tuple.  
+    /// Storages reserve capacity based on the lower bound of the iterator's size hint.  
+    /// New entities never reuse dead slots, the returned ids are contiguous and in insertion order.
     ///
     /// ### Example
     ///
@@ -284,7 +323,7 @@ impl Entities {
         }
 
         // have to use two loops because of self borrow
-        for (component, id) in iter.zip(repeat_with(|| self.generate())) {
+        for (component, id) in iter.zip(repeat_with(|| self.generate_new())) {
             AddEntity::add_entity(&mut storages, id, component);
         }
 
@@ -293,11 +332,87 @@ impl Entities {
             slice: &self.data[entities_len..],
         }
     }
-    /// Creates an iterator over all entities.
+    /// Reserves capacity for at least `additional` more entities.\
+    /// Deleted entities are reused before new ones are created, they don't need any capacity.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesViewMut, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// world.run(|mut entities: EntitiesViewMut| {
+    ///     entities.reserve(100);
+    ///
+    ///     for _ in 0..100 {
+    ///         entities.add_entity((), ());
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+    /// Creates an iterator over all living entities.\
+    /// Entities are yielded with their current generation, dead or deleted slots are skipped.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(());
+    /// let entity1 = world.add_entity(());
+    /// world.delete_entity(entity0);
+    ///
+    /// world.run(|entities: EntitiesView| {
+    ///     assert_eq!(entities.iter().collect::<Vec<_>>(), vec![entity1]);
+    ///     assert_eq!(entities.len(), 1);
+    /// });
+    /// ```
     #[inline]
     pub fn iter(&self) -> EntitiesIter<'_> {
         self.into_iter()
     }
+    /// Returns the number of living entities.\
+    /// This walks all entity slots, use [`Entities::is_alive`] to check a single entity.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+    /// Returns `true` if there is no living entity.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+    /// Creates an iterator over the living entities whose index is within `indices`.\
+    /// Entities are yielded with their current generation, dead or deleted slots are skipped.\
+    /// The range is clamped to the number of indices in use.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eids = (0..10).map(|_| world.add_entity(())).collect::<Vec<_>>();
+    /// world.delete_entity(eids[3]);
+    ///
+    /// world.run(|entities: EntitiesView| {
+    ///     let page = entities.iter_range(2..5).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(page, vec![eids[2], eids[4]]);
+    /// });
+    /// ```
+    pub fn iter_range(&self, indices: core::ops::Range<u64>) -> EntitiesIter<'_> {
+        let len = self.data.len();
+        let end = indices.end.min(len as u64) as usize;
+        let start = indices.start.min(end as u64) as usize;
+
+        EntitiesIter {
+            data: &self.data,
+            indices: start..end,
+        }
+    }
     /// Make the given entity alive.  
     /// Does nothing if an entity with a greater generation is already at this index.  
     /// Returns `true` if the entity is successfully spawned.
@@ -377,6 +492,13 @@ impl Entities {
         }
     }
 
+    /// Forgets all entities, the next generated `EntityId`s start back at index `0` and generation `0`.\
+    /// There shouldn't be any component left in the `World`.
+    pub(crate) fn reset(&mut self) {
+        self.data.clear();
+        self.list = None;
+    }
+
     /// Sets the on entity deletion callback.
     pub fn on_deletion(&mut self, f: impl FnMut(EntityId) + Send + Sync + 'static) {
         self.on_deletion = Some(Box::new(f));
@@ -389,6 +511,24 @@ impl Entities {
 }
 
 impl Storage for Entities {
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        other_all_storages.insert_cloned_storage(
+            storage_id,
+            Entities {
+                data: self.data.clone(),
+                list: self.list,
+                on_deletion: None,
+                #[cfg(feature = "entity_tag")]
+                tag: self.tag,
+            },
+        );
+
+        true
+    }
     fn clear(&mut self, _current: TrackingTimestamp) {
         if self.data.is_empty() {
             return;
@@ -429,6 +569,13 @@ impl Storage for Entities {
             .unwrap();
         self.list = Some((self.data.len() - end - 1, begin));
     }
+    fn shrink_to_fit(&mut self) -> usize {
+        let capacity = self.data.capacity();
+
+        self.data.shrink_to_fit();
+
+        (capacity - self.data.capacity()) * size_of::<EntityId>()
+    }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(StorageMemoryUsage {
             storage_name: type_name::<Self>().into(),
@@ -441,6 +588,9 @@ impl Storage for Entities {
     fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.iter().count())
+    }
     fn move_component_from(
         &mut self,
         _other_all_storages: &mut crate::AllStorages,
@@ -1,12 +1,18 @@
 #[cfg(feature = "serde1")]
 mod serde;
 
+use crate::all_storages::AllStorages;
+#[cfg(feature = "entity_tag")]
+use crate::world::World;
 use core::num::NonZeroU64;
 
 /// Handle to an entity.
 // the id is 64 bits long
-// <- 46 index -> <- 16 gen -> <- 2 meta ->
+// <- 16 gen -> <- 48 index ->
+// with the entity_tag feature
+// <- 16 gen -> <- 8 world tag -> <- 40 index ->
 // a generation of !0 is used as a dead entity
+// the world tag is 0 unless the World was built with WorldBuilder::with_entity_tag
 //
 // inserted and modified component are flagged using metadata
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -23,8 +29,16 @@ impl Default for EntityId {
 impl EntityId {
     // Number of bits used by the generation
     const GEN_LEN: u64 = 16;
-    const INDEX_LEN: u64 = 64 - Self::GEN_LEN;
+    // Number of bits used by the world tag
+    #[cfg(feature = "entity_tag")]
+    const TAG_LEN: u64 = 8;
+    #[cfg(not(feature = "entity_tag"))]
+    const TAG_LEN: u64 = 0;
+    const INDEX_LEN: u64 = 64 - Self::GEN_LEN - Self::TAG_LEN;
+    const GEN_SHIFT: u64 = Self::INDEX_LEN + Self::TAG_LEN;
     const INDEX_MASK: u64 = !(!0 << Self::INDEX_LEN);
+    #[cfg(feature = "entity_tag")]
+    const TAG_MASK: u64 = !(!0 << Self::TAG_LEN) << Self::INDEX_LEN;
     const GEN_MASK: u64 = !(!0 >> Self::GEN_LEN);
     const MAX_GEN: u16 = u16::MAX - 1;
 
@@ -51,7 +65,7 @@ impl EntityId {
     /// Returns the generation part of the `EntityId`.
     #[inline]
     pub fn gen(self) -> u16 {
-        ((self.0.get() & Self::GEN_MASK) >> Self::INDEX_LEN) as u16
+        ((self.0.get() & Self::GEN_MASK) >> Self::GEN_SHIFT) as u16
     }
     /// Increments the generation, returns Err if gen + 1 == gen::MAX().
     #[inline]
@@ -61,7 +75,7 @@ impl EntityId {
             self.0 = unsafe {
                 NonZeroU64::new_unchecked(
                     (self.0.get() & !Self::GEN_MASK)
-                        | (((self.gen() + 1) as u64) << Self::INDEX_LEN),
+                        | (((self.gen() + 1) as u64) << Self::GEN_SHIFT),
                 )
             };
             Ok(())
@@ -81,9 +95,57 @@ impl EntityId {
         assert!(index < Self::INDEX_MASK);
 
         EntityId(unsafe {
-            NonZeroU64::new_unchecked((index + 1) | ((gen as u64) << Self::INDEX_LEN))
+            NonZeroU64::new_unchecked((index + 1) | ((gen as u64) << Self::GEN_SHIFT))
         })
     }
+    /// Returns the tag of the [`World`] that generated this `EntityId`.\
+    /// It is `0` unless the [`World`] was built with [`WorldBuilder::with_entity_tag`].
+    ///
+    /// [`World`]: crate::World
+    /// [`WorldBuilder::with_entity_tag`]: crate::world::WorldBuilder::with_entity_tag
+    #[cfg(feature = "entity_tag")]
+    #[inline]
+    pub fn world_tag(self) -> u8 {
+        ((self.0.get() & Self::TAG_MASK) >> Self::INDEX_LEN) as u8
+    }
+    /// Returns this `EntityId` with its world tag replaced by `tag`.
+    #[cfg(feature = "entity_tag")]
+    #[inline]
+    pub(crate) fn with_world_tag(self, tag: u8) -> Self {
+        // SAFE never zero, the index part is untouched
+        EntityId(unsafe {
+            NonZeroU64::new_unchecked(
+                (self.0.get() & !Self::TAG_MASK) | ((tag as u64) << Self::INDEX_LEN),
+            )
+        })
+    }
+    /// Returns `true` if this `EntityId` was generated by `world`, based on the world tag.
+    ///
+    /// Only [`World`]s built with [`WorldBuilder::with_entity_tag`] tag their entities,
+    /// all other [`World`]s share the tag `0`.\
+    /// The liveness of the entity is not checked.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world1 = World::builder().with_entity_tag().unwrap().build();
+    /// let mut world2 = World::builder().with_entity_tag().unwrap().build();
+    ///
+    /// let entity = world2.add_entity(());
+    ///
+    /// assert!(entity.belongs_to(&world2));
+    /// assert!(!entity.belongs_to(&world1));
+    /// ```
+    ///
+    /// [`World`]: crate::World
+    /// [`WorldBuilder::with_entity_tag`]: crate::world::WorldBuilder::with_entity_tag
+    #[cfg(feature = "entity_tag")]
+    #[inline]
+    pub fn belongs_to(self, world: &World) -> bool {
+        self.world_tag() == world.entity_tag()
+    }
     /// Build a new `EntityId` with the given index and generation.
     #[inline]
     pub const fn new_from_index_and_gen(index: u64, gen: u16) -> Self {
@@ -153,15 +215,175 @@ impl EntityId {
     pub fn from_inner(inner: u64) -> Option<EntityId> {
         Some(EntityId(NonZeroU64::new(inner)?))
     }
+    /// Returns the index and generation of this `EntityId` packed in a `u64`.\
+    /// It can be turned back into an `EntityId` with [`EntityId::from_bits`].
+    ///
+    /// This encoding doesn't depend on the internal representation returned by [`EntityId::inner`]
+    /// and won't change across versions:
+    ///
+    /// - bits `0..48`: index
+    /// - bits `48..64`: generation
+    ///
+    /// The world tag added by the `entity_tag` feature isn't part of the encoding.\
+    /// A dead `EntityId` is encoded as `u64::MAX`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{EntityId, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(());
+    /// let entity = world.add_entity(());
+    /// let bits = entity.to_bits();
+    ///
+    /// assert_eq!(bits, 1);
+    /// assert_eq!(EntityId::from_bits(bits), entity);
+    /// assert!(world.is_entity_alive(EntityId::from_bits(bits)));
+    /// ```
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        if self == EntityId::dead() {
+            u64::MAX
+        } else {
+            self.index() | ((self.gen() as u64) << 48)
+        }
+    }
+    /// Builds an `EntityId` from the output of [`EntityId::to_bits`].\
+    /// The result is untagged, see [`EntityId::to_bits`].
+    ///
+    /// ### Panics
+    ///
+    /// - The index in `bits` is too large to be stored in an `EntityId`.
+    #[track_caller]
+    #[inline]
+    pub fn from_bits(bits: u64) -> EntityId {
+        if bits == u64::MAX {
+            EntityId::dead()
+        } else {
+            EntityId::new_from_parts(bits & !(!0 << 48), (bits >> 48) as u16)
+        }
+    }
+    /// Returns a value formatting this `EntityId` alongside its debug name.\
+    /// Entities without a name are formatted like a regular `EntityId`.
+    ///
+    /// Names are set with [`AllStorages::set_entity_name`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let player = all_storages.add_entity(());
+    /// let enemy = all_storages.add_entity(());
+    /// all_storages.set_entity_name(player, "Player");
+    ///
+    /// assert_eq!(format!("{:?}", player.debug_named(&all_storages)), "Player (EId(0.0))");
+    /// assert_eq!(format!("{:?}", enemy.debug_named(&all_storages)), "EId(1.0)");
+    /// ```
+    pub fn debug_named(self, all_storages: &AllStorages) -> NamedEntityId<'_> {
+        NamedEntityId {
+            entity: self,
+            name: all_storages.entity_name(self),
+        }
+    }
+    /// Returns the shard, in `0..shards`, this `EntityId` is placed in.
+    ///
+    /// Only the index is used, all generations of an index land in the same shard.
+    /// When `shards` grows by one, an index either keeps its shard or moves to the new one.
+    ///
+    /// The result only depends on `index` and `shards`, it is identical across machines and versions of shipyard.
+    /// It is computed using a SplitMix64 finalizer followed by a [jump consistent hash](https://arxiv.org/abs/1406.2294),
+    /// operations on `key` are done on `u64` and wrap on overflow, `b` and `j` are `u128`:
+    ///
+    /// ```text
+    /// key = index
+    /// key = (key ^ (key >> 30)) * 0xbf58476d1ce4e5b9
+    /// key = (key ^ (key >> 27)) * 0x94d049bb133111eb
+    /// key = key ^ (key >> 31)
+    ///
+    /// b = 0
+    /// j = 0
+    /// while j < shards {
+    ///     b = j
+    ///     key = key * 2862933555777941757 + 1
+    ///     j = ((b + 1) << 31) / ((key >> 33) + 1)
+    /// }
+    /// return b
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// - `shards` is `0`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::EntityId;
+    ///
+    /// let id = EntityId::new_from_index_and_gen(554, 0);
+    ///
+    /// assert_eq!(id.shard_key(10), 7);
+    /// assert_eq!(id.shard_key(100), 30);
+    /// ```
+    pub fn shard_key(self, shards: u64) -> u64 {
+        assert!(shards != 0, "shard_key needs at least one shard.");
+
+        let mut key = self.index();
+        key = (key ^ (key >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        key = (key ^ (key >> 27)).wrapping_mul(0x94d049bb133111eb);
+        key ^= key >> 31;
+
+        let mut b: u128 = 0;
+        let mut j: u128 = 0;
+        while j < shards as u128 {
+            b = j;
+            key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+            j = ((b + 1) << 31) / ((key >> 33) as u128 + 1);
+        }
+
+        b as u64
+    }
+}
+
+/// [`EntityId`] formatted with its debug name, returned by [`EntityId::debug_named`].
+pub struct NamedEntityId<'a> {
+    entity: EntityId,
+    name: Option<&'a str>,
+}
+
+impl core::fmt::Debug for NamedEntityId<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name {
+            write!(f, "{} ({:?})", name, self.entity)
+        } else {
+            core::fmt::Debug::fmt(&self.entity, f)
+        }
+    }
 }
 
 impl core::fmt::Debug for EntityId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if *self == EntityId::dead() {
-            f.write_str("EId(dead)")
-        } else {
-            write!(f, "EId({}.{})", self.index(), self.gen())
+            return f.write_str("EId(dead)");
         }
+
+        #[cfg(feature = "entity_tag")]
+        if f.alternate() && self.world_tag() != 0 {
+            return write!(
+                f,
+                "EId(w{}:{}.{})",
+                self.world_tag(),
+                self.index(),
+                self.gen()
+            );
+        }
+
+        write!(f, "EId({}.{})", self.index(), self.gen())
     }
 }
 
@@ -182,3 +404,49 @@ fn entity_id() {
     assert_eq!(entity_id.index(), 554);
     assert_eq!(entity_id.gen(), 3);
 }
+
+#[cfg(feature = "entity_tag")]
+#[test]
+fn world_tag() {
+    let mut entity_id = EntityId::new_from_parts(701, 3).with_world_tag(255);
+    assert_eq!(entity_id.index(), 701);
+    assert_eq!(entity_id.gen(), 3);
+    assert_eq!(entity_id.world_tag(), 255);
+    entity_id.bump_gen().unwrap();
+    entity_id.set_index(554);
+    assert_eq!(entity_id.index(), 554);
+    assert_eq!(entity_id.gen(), 4);
+    assert_eq!(entity_id.world_tag(), 255);
+    assert_eq!(alloc::format!("{:?}", entity_id), "EId(554.4)");
+    assert_eq!(alloc::format!("{:#?}", entity_id), "EId(w255:554.4)");
+}
+
+#[test]
+fn bits() {
+    let entity_id = EntityId::new_from_parts(701, 3);
+    assert_eq!(entity_id.to_bits(), 701 | (3 << 48));
+    assert_eq!(EntityId::from_bits(entity_id.to_bits()), entity_id);
+    assert_eq!(
+        EntityId::from_bits(EntityId::dead().to_bits()),
+        EntityId::dead()
+    );
+}
+
+#[test]
+fn shard_key() {
+    for index in [0, 1, 2, 3, 701, 554, 12345] {
+        assert_eq!(EntityId::new_from_parts(index, 0).shard_key(1), 0);
+    }
+
+    // These values are part of the stability guarantee, they must never change.
+    assert_eq!(EntityId::new(1).shard_key(10), 9);
+    assert_eq!(EntityId::new(1).shard_key(100), 41);
+    assert_eq!(EntityId::new(2).shard_key(10), 7);
+    assert_eq!(EntityId::new(554).shard_key(2), 1);
+    assert_eq!(EntityId::new(12345).shard_key(10), 4);
+    assert_eq!(EntityId::new(12345).shard_key(100), 46);
+
+    let mut id = EntityId::new(554);
+    id.bump_gen().unwrap();
+    assert_eq!(id.shard_key(100), 30);
+}
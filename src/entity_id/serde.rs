@@ -7,7 +7,10 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+#[cfg(not(feature = "entity_tag"))]
 const FIELDS: &[&str] = &["index", "gen"];
+#[cfg(feature = "entity_tag")]
+const FIELDS: &[&str] = &["index", "gen", "tag"];
 
 impl Serialize for EntityId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -15,9 +18,21 @@ impl Serialize for EntityId {
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            let mut ser_struct = serializer.serialize_struct("EntityId", 2)?;
+            // untagged ids serialize the same with and without the entity_tag feature
+            #[cfg(not(feature = "entity_tag"))]
+            let len = FIELDS.len();
+            #[cfg(feature = "entity_tag")]
+            let len = if self.world_tag() == 0 { 2 } else { 3 };
+
+            let mut ser_struct = serializer.serialize_struct("EntityId", len)?;
             ser_struct.serialize_field(FIELDS[0], &(self.index()))?;
             ser_struct.serialize_field(FIELDS[1], &(self.gen()))?;
+            #[cfg(feature = "entity_tag")]
+            if self.world_tag() == 0 {
+                ser_struct.skip_field(FIELDS[2])?;
+            } else {
+                ser_struct.serialize_field(FIELDS[2], &(self.world_tag()))?;
+            }
             ser_struct.end()
         } else {
             let clone = self.clone();
@@ -34,6 +49,8 @@ impl<'de> Deserialize<'de> for EntityId {
         enum Field {
             Index,
             Generation,
+            #[cfg(feature = "entity_tag")]
+            Tag,
         }
 
         impl<'de> Deserialize<'de> for Field {
@@ -47,7 +64,12 @@ impl<'de> Deserialize<'de> for EntityId {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                        formatter.write_str("`index` or `gen`")
+                        #[cfg(not(feature = "entity_tag"))]
+                        let expected = "`index` or `gen`";
+                        #[cfg(feature = "entity_tag")]
+                        let expected = "`index`, `gen` or `tag`";
+
+                        formatter.write_str(expected)
                     }
 
                     fn visit_str<E>(self, value: &str) -> Result<Field, E>
@@ -57,6 +79,8 @@ impl<'de> Deserialize<'de> for EntityId {
                         match value {
                             "index" => Ok(Field::Index),
                             "gen" => Ok(Field::Generation),
+                            #[cfg(feature = "entity_tag")]
+                            "tag" => Ok(Field::Tag),
                             _ => Err(de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -87,7 +111,16 @@ impl<'de> Deserialize<'de> for EntityId {
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
 
-                Ok(EntityId::new_from_parts(index, generation))
+                let entity = EntityId::new_from_parts(index, generation);
+
+                #[cfg(feature = "entity_tag")]
+                let entity = {
+                    let tag: Option<u8> = seq.next_element()?;
+
+                    entity.with_world_tag(tag.unwrap_or(0))
+                };
+
+                Ok(entity)
             }
 
             fn visit_map<V>(self, mut map: V) -> Result<EntityId, V::Error>
@@ -96,6 +129,8 @@ impl<'de> Deserialize<'de> for EntityId {
             {
                 let mut index = None;
                 let mut generation = None;
+                #[cfg(feature = "entity_tag")]
+                let mut tag = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -111,13 +146,25 @@ impl<'de> Deserialize<'de> for EntityId {
                             }
                             generation = Some(map.next_value()?);
                         }
+                        #[cfg(feature = "entity_tag")]
+                        Field::Tag => {
+                            if tag.is_some() {
+                                return Err(de::Error::duplicate_field("tag"));
+                            }
+                            tag = Some(map.next_value()?);
+                        }
                     }
                 }
 
                 let index = index.ok_or_else(|| de::Error::missing_field("index"))?;
                 let generation = generation.ok_or_else(|| de::Error::missing_field("gen"))?;
 
-                Ok(EntityId::new_from_parts(index, generation))
+                let entity = EntityId::new_from_parts(index, generation);
+
+                #[cfg(feature = "entity_tag")]
+                let entity = entity.with_world_tag(tag.unwrap_or(0));
+
+                Ok(entity)
             }
         }
 
@@ -148,3 +195,22 @@ fn bincode() {
     let entity = bincode::deserialize::<EntityId>(&bytes).unwrap();
     assert_eq!(entity, EntityId::new_from_parts(10, 2));
 }
+
+#[cfg(feature = "entity_tag")]
+#[test]
+fn serde_json_tagged() {
+    let entity = EntityId::new_from_index_and_gen(10, 2).with_world_tag(3);
+
+    let string = serde_json::to_string(&entity).unwrap();
+    assert_eq!(r#"{"index":10,"gen":2,"tag":3}"#, string);
+    assert_eq!(
+        serde_json::de::from_str::<EntityId>(&string).unwrap(),
+        entity
+    );
+
+    let untagged = serde_json::de::from_str::<EntityId>(r#"{"index":10,"gen":2}"#).unwrap();
+    assert_eq!(untagged, EntityId::new_from_index_and_gen(10, 2));
+
+    let bytes = bincode::serialize(&entity).unwrap();
+    assert_eq!(bincode::deserialize::<EntityId>(&bytes).unwrap(), entity);
+}
@@ -1,6 +1,7 @@
 //! All error types.
 
-use crate::entity_id::EntityId;
+use crate::all_storages::AllStorages;
+use crate::entity_id::{EntityId, NamedEntityId};
 use crate::scheduler::info::TypeInfo;
 use crate::scheduler::Label;
 use crate::storage::StorageId;
@@ -22,6 +23,8 @@ pub enum Borrow {
     WrongThread,
     /// The Storage of a `!Sync` component was accessed from multiple threads at the same time.
     MultipleThreads,
+    /// The Storage was frozen when an exclusive borrow occurred.
+    Frozen,
 }
 
 #[cfg(feature = "std")]
@@ -40,6 +43,7 @@ impl Debug for Borrow {
             Borrow::MultipleThreads => f.write_str(
                 "Can't access from multiple threads at the same time because it's !Sync.",
             ),
+            Borrow::Frozen => f.write_str("Cannot mutably borrow while frozen."),
         }
     }
 }
@@ -73,6 +77,13 @@ pub enum GetStorage {
         id: StorageId,
         tracking: &'static str,
     },
+    /// The storage is not part of the [`ScopedWorld`](crate::world::ScopedWorld)'s scope.
+    NotInScope {
+        #[allow(missing_docs)]
+        name: Option<&'static str>,
+        #[allow(missing_docs)]
+        id: StorageId,
+    },
     /// Error returned by a custom view.
     #[cfg(feature = "std")]
     Custom(Box<dyn Error + Send + Sync>),
@@ -133,6 +144,16 @@ impl PartialEq for GetStorage {
                     tracking: r_tracking,
                 },
             ) => l_name == r_name && l_id == r_id && l_tracking == r_tracking,
+            (
+                GetStorage::NotInScope {
+                    name: l_name,
+                    id: l_id,
+                },
+                GetStorage::NotInScope {
+                    name: r_name,
+                    id: r_id,
+                },
+            ) => l_name == r_name && l_id == r_id,
             _ => false,
         }
     }
@@ -161,6 +182,7 @@ impl Debug for GetStorage {
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
                     Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                    Borrow::Frozen => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's frozen.", name)),
                 }
             } else {
                 match borrow {
@@ -170,6 +192,7 @@ impl Debug for GetStorage {
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
                     Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
+                    Borrow::Frozen => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's frozen.", id)),
                 }
             }
             GetStorage::Entities(borrow) => match borrow {
@@ -189,6 +212,11 @@ impl Debug for GetStorage {
             } else {
                 f.write_fmt(format_args!("{} tracking is not enabled for {:?} storage.", tracking, id))
             }
+            GetStorage::NotInScope { name, id } => if let Some(name) = name {
+                f.write_fmt(format_args!("{} storage is not in scope. Add its StorageId to the list given to World::scope.", name))
+            } else {
+                f.write_fmt(format_args!("{:?} storage is not in scope. Add its StorageId to the list given to World::scope.", id))
+            }
             GetStorage::Custom(err) => {
                 f.write_fmt(format_args!("Storage borrow failed with a custom error, {:?}.", err))
             }
@@ -267,6 +295,110 @@ impl Display for AddComponent {
     }
 }
 
+/// Error returned by [`AllStorages::clone_entity`] and [`World::clone_entity`].
+///
+/// [`AllStorages::clone_entity`]: crate::all_storages::AllStorages::clone_entity()
+/// [`World::clone_entity`]: crate::world::World::clone_entity()
+#[derive(Clone, PartialEq, Eq)]
+pub enum CloneEntity {
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+    /// Names of the storages holding a component of the entity that can't be cloned.
+    NotClone(Vec<Cow<'static, str>>),
+}
+
+#[cfg(feature = "std")]
+impl Error for CloneEntity {}
+
+impl Debug for CloneEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CloneEntity::EntityIsNotAlive => f.write_str("Entity has to be alive to be cloned."),
+            CloneEntity::NotClone(names) => f.write_fmt(format_args!(
+                "{:?} storages cannot be cloned. Set Component::CLONE.",
+                names
+            )),
+        }
+    }
+}
+
+impl Display for CloneEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`AllStorages::spawn_columns`] and [`World::spawn_columns`].
+///
+/// [`AllStorages::spawn_columns`]: crate::all_storages::AllStorages::spawn_columns()
+/// [`World::spawn_columns`]: crate::world::World::spawn_columns()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SpawnColumns {
+    /// A column didn't yield the same number of components as the first column.
+    MismatchedLength {
+        /// Number of components yielded by the first column.
+        expected: usize,
+        /// Number of components yielded by the mismatched column.
+        found: usize,
+        /// Index of the mismatched column.
+        column: usize,
+    },
+    /// Multiple columns yield the same component type, contains its name.
+    DuplicateComponent(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl Error for SpawnColumns {}
+
+impl Debug for SpawnColumns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SpawnColumns::MismatchedLength {
+                expected,
+                found,
+                column,
+            } => f.write_fmt(format_args!(
+                "Column {} yielded {} components but the first column yielded {}.",
+                column, found, expected
+            )),
+            SpawnColumns::DuplicateComponent(name) => f.write_fmt(format_args!(
+                "Multiple columns yield {} components, each column has to yield a different component type.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for SpawnColumns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Returned by [`WorldBuilder::with_entity_tag`] when all 255 tags are used by living [`World`](crate::World)s.
+///
+/// [`WorldBuilder::with_entity_tag`]: crate::world::WorldBuilder::with_entity_tag()
+#[cfg(feature = "entity_tag")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EntityTag;
+
+#[cfg(all(feature = "entity_tag", feature = "std"))]
+impl Error for EntityTag {}
+
+#[cfg(feature = "entity_tag")]
+impl Debug for EntityTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str("All 255 entity tags are in use, drop a tagged World to free one.")
+    }
+}
+
+#[cfg(feature = "entity_tag")]
+impl Display for EntityTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error type returned by [`Workload::add_to_world`].
 ///
 /// [`Workload::add_to_world`]: crate::Workload::add_to_world()
@@ -278,6 +410,8 @@ pub enum AddWorkload {
     Borrow,
     /// This workload cannot be created.
     ImpossibleRequirements(ImpossibleRequirements),
+    /// A system of this workload is invalid.
+    InvalidSystem(InvalidSystem),
     /// A system declared some requirements that are not met.
     MissingInWorkload(Box<dyn Label>, Vec<Box<dyn Label>>),
     /// A system declared some requirements that are not met.
@@ -301,6 +435,7 @@ impl PartialEq for AddWorkload {
             (AddWorkload::ImpossibleRequirements(l0), AddWorkload::ImpossibleRequirements(r0)) => {
                 l0 == r0
             }
+            (AddWorkload::InvalidSystem(l0), AddWorkload::InvalidSystem(r0)) => l0 == r0,
             (AddWorkload::MissingInWorkload(l0, l1), AddWorkload::MissingInWorkload(r0, r1)) => {
                 l0 == r0 && l1 == r1
             }
@@ -338,6 +473,7 @@ impl Debug for AddWorkload {
                 f.write_str("Cannot mutably borrow the scheduler while it's already borrowed.")
             }
             AddWorkload::ImpossibleRequirements(err) => Debug::fmt(err, f),
+            AddWorkload::InvalidSystem(err) => Debug::fmt(err, f),
             AddWorkload::MissingInWorkload(system_name, missing_in_workload) => {
                 f.write_fmt(format_args!(
                     "System({:?}) is missing some systems in workload: {:?}",
@@ -364,6 +500,7 @@ impl Debug for AddWorkload {
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
                         Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                        Borrow::Frozen => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's frozen.", name)),
                     }
                 } else {
                     match borrow {
@@ -373,6 +510,7 @@ impl Debug for AddWorkload {
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
                         Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
+                        Borrow::Frozen => f.write_fmt(format_args!("Cannot mutably borrow {:?} storage while it's frozen.", id)),
                     }
                 }
             }
@@ -448,6 +586,28 @@ impl RunWorkload {
             _ => None,
         }
     }
+    /// Returns the custom error if it's an `E`, otherwise gives back `self`.
+    #[cfg(feature = "std")]
+    pub fn downcast_custom<E: Error + 'static>(self) -> Result<E, RunWorkload> {
+        match self {
+            RunWorkload::Run((system, Run::Custom(error))) => match error.downcast::<E>() {
+                Ok(error) => Ok(*error),
+                Err(error) => Err(RunWorkload::Run((system, Run::Custom(error)))),
+            },
+            _ => Err(self),
+        }
+    }
+    /// Returns the custom error if it's an `E`, otherwise gives back `self`.
+    #[cfg(not(feature = "std"))]
+    pub fn downcast_custom<E: 'static>(self) -> Result<E, RunWorkload> {
+        match self {
+            RunWorkload::Run((system, Run::Custom(error))) => match error.downcast::<E>() {
+                Ok(error) => Ok(*error),
+                Err(error) => Err(RunWorkload::Run((system, Run::Custom(error)))),
+            },
+            _ => Err(self),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -568,8 +728,70 @@ impl Display for MissingComponent {
     }
 }
 
+impl MissingComponent {
+    /// Returns a value formatting this error with the entity's debug name.\
+    /// Entities without a name are formatted like a regular `EntityId`.
+    ///
+    /// Names are set with [`AllStorages::set_entity_name`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Get, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let player = all_storages.add_entity(());
+    /// all_storages.set_entity_name(player, "Player");
+    ///
+    /// let healths = all_storages.borrow::<View<Health>>().unwrap();
+    /// let error = healths.get(player).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", error.debug_named(&all_storages)),
+    ///     format!(
+    ///         "Player (EId(0.0)) does not have a {} component.",
+    ///         core::any::type_name::<Health>()
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`AllStorages::set_entity_name`]: crate::AllStorages::set_entity_name
+    pub fn debug_named(self, all_storages: &AllStorages) -> NamedMissingComponent<'_> {
+        NamedMissingComponent {
+            id: self.id.debug_named(all_storages),
+            name: self.name,
+        }
+    }
+}
+
+/// [`MissingComponent`] formatted with the entity's debug name, returned by [`MissingComponent::debug_named`].
+pub struct NamedMissingComponent<'a> {
+    id: NamedEntityId<'a>,
+    name: &'static str,
+}
+
+impl Debug for NamedMissingComponent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!(
+            "{:?} does not have a {} component.",
+            self.id, self.name
+        ))
+    }
+}
+
+impl Display for NamedMissingComponent<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Returned when trying to add an invalid system to a workload.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum InvalidSystem {
     /// `AllStorages` borrowed alongside another storage.
     AllStorages,
@@ -579,6 +801,10 @@ pub enum InvalidSystem {
     MultipleViewsMut,
     /// System returning `Workload`
     WorkloadUsedAsSystem(&'static str),
+    /// Two different systems with the same name.
+    ConflictingName(Cow<'static, str>),
+    /// Systems required to run before or after each other in a cycle, starts and ends with the same system.
+    CyclicDependency(Vec<Cow<'static, str>>),
 }
 
 #[cfg(feature = "std")]
@@ -591,6 +817,8 @@ impl Debug for InvalidSystem {
             InvalidSystem::MultipleViews => f.write_str("Multiple views of the same storage including an exclusive borrow, consider removing the shared borrow."),
             InvalidSystem::MultipleViewsMut => f.write_str("Multiple exclusive views of the same storage, consider removing one."),
             InvalidSystem::WorkloadUsedAsSystem(system_name) => f.write_fmt(format_args!("Workload used as a system, you should call it `{}()`.", system_name)),
+            InvalidSystem::ConflictingName(system_name) => f.write_fmt(format_args!("Two different systems are named {}.", system_name)),
+            InvalidSystem::CyclicDependency(systems) => f.write_fmt(format_args!("Systems are required to run before and after each other: {}.", systems.join(" -> "))),
         }
     }
 }
@@ -626,6 +854,7 @@ impl Debug for UniqueRemove {
             UniqueRemove::StorageBorrow((name, borrow)) => match borrow {
                 Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
                 Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                Borrow::Frozen => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's frozen.", name)),
                 _ => unreachable!()
             }
         }
@@ -671,6 +900,53 @@ impl Display for Apply {
     }
 }
 
+/// Error returned by [`World::try_clone`](crate::World::try_clone).
+#[derive(Clone, PartialEq, Eq)]
+pub enum CloneWorld {
+    /// `AllStorages` was already borrowed exclusively.
+    AllStoragesBorrow(Borrow),
+    /// A storage was already borrowed exclusively.
+    StorageBorrow {
+        #[allow(missing_docs)]
+        id: StorageId,
+        #[allow(missing_docs)]
+        borrow: Borrow,
+    },
+    /// A storage doesn't support cloning.
+    NotClone {
+        #[allow(missing_docs)]
+        name: Cow<'static, str>,
+        #[allow(missing_docs)]
+        id: StorageId,
+    },
+}
+
+#[cfg(feature = "std")]
+impl Error for CloneWorld {}
+
+impl Debug for CloneWorld {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CloneWorld::AllStoragesBorrow(borrow) => {
+                f.write_fmt(format_args!("Cannot borrow AllStorages: {:?}", borrow))
+            }
+            CloneWorld::StorageBorrow { id, borrow } => {
+                f.write_fmt(format_args!("Cannot borrow {:?} storage: {:?}", id, borrow))
+            }
+            CloneWorld::NotClone { name, .. } => f.write_fmt(format_args!(
+                "{} storage cannot be cloned. Set Component::CLONE or Unique::CLONE.",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for CloneWorld {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`are_all_uniques_present_in_world`].
 ///
 /// [`are_all_uniques_present_in_world`]: crate::Workload::are_all_uniques_present_in_world()
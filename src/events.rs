@@ -0,0 +1,158 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::Unique;
+use crate::error;
+use crate::scheduler::info::TypeInfo;
+use crate::tracking::TrackingTimestamp;
+use crate::views::{UniqueView, UniqueViewMut};
+use alloc::vec::Vec;
+
+/// Double buffered event queue, stored as a unique.
+///
+/// Register it with [`World::add_event`] then use [`EventWriter`] and [`EventReader`] to access it.\
+/// Events live until the second [`World::update_events`] call after they were sent.
+///
+/// [`World::add_event`]: crate::World::add_event
+/// [`World::update_events`]: crate::World::update_events
+pub struct Events<E> {
+    previous: Vec<(TrackingTimestamp, E)>,
+    latest: Vec<(TrackingTimestamp, E)>,
+}
+
+impl<E: Send + Sync + 'static> Unique for Events<E> {}
+
+impl<E> Default for Events<E> {
+    fn default() -> Self {
+        Events {
+            previous: Vec::new(),
+            latest: Vec::new(),
+        }
+    }
+}
+
+impl<E> Events<E> {
+    /// Drops the events sent before the previous update and swaps the buffers.
+    pub fn update(&mut self) {
+        core::mem::swap(&mut self.previous, &mut self.latest);
+        self.latest.clear();
+    }
+    /// Returns the number of events in both buffers.
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.latest.len()
+    }
+    /// Returns `true` if both buffers are empty.
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty() && self.latest.is_empty()
+    }
+    /// Drops all events.
+    pub fn clear(&mut self) {
+        self.previous.clear();
+        self.latest.clear();
+    }
+    fn iter_all(&self) -> impl Iterator<Item = &(TrackingTimestamp, E)> + '_ {
+        self.previous.iter().chain(&self.latest)
+    }
+}
+
+/// Sends events of type `E`.
+///
+/// The events channel has to be registered with [`World::add_event`].
+///
+/// [`World::add_event`]: crate::World::add_event
+pub struct EventWriter<'v, E: Send + Sync + 'static> {
+    events: UniqueViewMut<'v, Events<E>>,
+}
+
+impl<E: Send + Sync + 'static> EventWriter<'_, E> {
+    /// Sends an event, readers will see it until the second [`World::update_events`] call.
+    ///
+    /// [`World::update_events`]: crate::World::update_events
+    pub fn push(&mut self, event: E) {
+        let current = self.events.current;
+
+        self.events.latest.push((current, event));
+    }
+}
+
+impl<E: Send + Sync + 'static> Borrow for EventWriter<'_, E> {
+    type View<'a> = EventWriter<'a, E>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(EventWriter {
+            events: UniqueViewMut::borrow(all_storages, all_borrow, last_run, current)?,
+        })
+    }
+}
+
+unsafe impl<E: Send + Sync + 'static> BorrowInfo for EventWriter<'_, E> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        UniqueViewMut::<Events<E>>::borrow_info(info);
+    }
+
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+/// Reads events of type `E`.
+///
+/// Inside a workload, only the events sent since the last run of the system are read.\
+/// Outside workloads all events still alive are read.
+///
+/// The events channel has to be registered with [`World::add_event`].
+///
+/// [`World::add_event`]: crate::World::add_event
+pub struct EventReader<'v, E: Send + Sync + 'static> {
+    events: UniqueView<'v, Events<E>>,
+    last_run: Option<TrackingTimestamp>,
+}
+
+impl<E: Send + Sync + 'static> EventReader<'_, E> {
+    /// Iterates the events in the order they were sent.
+    pub fn read(&self) -> impl Iterator<Item = &E> + '_ {
+        let current = self.events.current;
+        let last_run = self.last_run;
+
+        self.events
+            .iter_all()
+            .filter(move |(timestamp, _)| match last_run {
+                Some(last_run) => timestamp.is_within(last_run, current),
+                None => true,
+            })
+            .map(|(_, event)| event)
+    }
+    /// Returns `true` if there is no event to read.
+    pub fn is_empty(&self) -> bool {
+        self.read().next().is_none()
+    }
+}
+
+impl<E: Send + Sync + 'static> Borrow for EventReader<'_, E> {
+    type View<'a> = EventReader<'a, E>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(EventReader {
+            events: UniqueView::borrow(all_storages, all_borrow, last_run, current)?,
+            last_run,
+        })
+    }
+}
+
+unsafe impl<E: Send + Sync + 'static> BorrowInfo for EventReader<'_, E> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        UniqueView::<Events<E>>::borrow_info(info);
+    }
+
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
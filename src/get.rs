@@ -99,6 +99,8 @@ impl<'a, 'b, T: Component, Track: Tracking> Get for &'b mut ViewMut<'a, T, Track
                 .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
             current: self.current,
             data: unsafe { data.get_unchecked_mut(index) },
+            compare: T::MODIFICATION_COMPARE,
+            previous: None,
         })
     }
 }
@@ -0,0 +1,397 @@
+//! Parent/child relationships stored as components.
+//!
+//! An entity with children owns a [`Children`] component, each child owns a [`Parent`] component.\
+//! Siblings are linked in a circular list, attaching a child adds it after its last sibling.
+//!
+//! The links are maintained by [`HierarchyViewMut`].
+//! Deleting an entity with [`AllStorages::delete_entity`] or [`AllStorages::retain_entities`] detaches it from its parent
+//! and detaches its children, they become roots. Use [`AllStorages::delete_hierarchy`] to delete the children too.
+
+use crate::add_component::AddComponent;
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::remove::Remove;
+use crate::scheduler::info::TypeInfo;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::track;
+use crate::tracking::TrackingTimestamp;
+use crate::views::{EntitiesView, ViewMut};
+// macro not module
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Component of an entity attached to a parent.
+pub struct Parent {
+    parent: EntityId,
+    prev: EntityId,
+    next: EntityId,
+}
+
+impl Component for Parent {
+    type Tracking = track::Untracked;
+}
+
+impl Parent {
+    /// Returns the parent entity.
+    #[inline]
+    pub fn get(&self) -> EntityId {
+        self.parent
+    }
+}
+
+/// Component of an entity with at least one child.
+pub struct Children {
+    len: usize,
+    first: EntityId,
+}
+
+impl Component for Children {
+    type Tracking = track::Untracked;
+}
+
+impl Children {
+    /// Returns the number of direct children.
+    #[inline]
+    #[allow(clippy::len_without_is_empty, reason = "Children is never empty")]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns the first child.
+    #[inline]
+    pub fn first(&self) -> EntityId {
+        self.first
+    }
+}
+
+/// Exclusive view over [`Parent`] and [`Children`] storages, keeping the hierarchy links valid.
+///
+/// ### Example
+/// ```
+/// use shipyard::hierarchy::HierarchyViewMut;
+/// use shipyard::World;
+///
+/// let mut world = World::new();
+///
+/// let [root, child, grandchild] = [(); 3].map(|_| world.add_entity(()));
+///
+/// let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+///
+/// hierarchy.attach_child(root, child);
+/// hierarchy.attach_child(child, grandchild);
+///
+/// assert_eq!(hierarchy.parent(grandchild), Some(child));
+/// assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [child]);
+/// assert_eq!(
+///     hierarchy.descendants(root).collect::<Vec<_>>(),
+///     [child, grandchild]
+/// );
+/// ```
+pub struct HierarchyViewMut<'v> {
+    entities: EntitiesView<'v>,
+    parents: ViewMut<'v, Parent>,
+    children: ViewMut<'v, Children>,
+}
+
+impl HierarchyViewMut<'_> {
+    /// Attaches `child` to `parent`, after its last child.\
+    /// If `child` already had a parent, it is detached first.
+    ///
+    /// ### Panics
+    ///
+    /// - `parent` or `child` is not alive.
+    /// - `child` is `parent` or one of its ancestors.
+    #[track_caller]
+    pub fn attach_child(&mut self, parent: EntityId, child: EntityId) {
+        assert!(
+            self.entities.is_alive(parent) && self.entities.is_alive(child),
+            "Entities have to be alive to be attached."
+        );
+        assert!(
+            parent != child && !self.ancestors(parent).any(|ancestor| ancestor == child),
+            "Attaching {:?} to {:?} would create a cycle.",
+            child,
+            parent
+        );
+
+        self.detach(child);
+
+        if let Some(children) = self.children.private_get(parent) {
+            let next = children.first;
+            let prev = self.parents[next].prev;
+
+            self.parents[prev].next = child;
+            self.parents[next].prev = child;
+            self.children[parent].len += 1;
+
+            self.parents
+                .add_component_unchecked(child, Parent { parent, prev, next });
+        } else {
+            self.parents.add_component_unchecked(
+                child,
+                Parent {
+                    parent,
+                    prev: child,
+                    next: child,
+                },
+            );
+            self.children.add_component_unchecked(
+                parent,
+                Children {
+                    len: 1,
+                    first: child,
+                },
+            );
+        }
+    }
+    /// Detaches `child` from its parent, its own children stay attached to it.\
+    /// Returns the previous parent.
+    pub fn detach(&mut self, child: EntityId) -> Option<EntityId> {
+        let Parent { parent, prev, next } = self.parents.remove(child)?;
+
+        let children = &mut self.children[parent];
+        children.len -= 1;
+
+        if children.len == 0 {
+            self.children.remove(parent);
+        } else {
+            if children.first == child {
+                children.first = next;
+            }
+
+            self.parents[prev].next = next;
+            self.parents[next].prev = prev;
+        }
+
+        Some(parent)
+    }
+    /// Detaches `entity` from its parent and detaches all its children.
+    fn unlink(&mut self, entity: EntityId) {
+        self.detach(entity);
+
+        while let Some(children) = self.children.private_get(entity) {
+            let first = children.first;
+
+            self.detach(first);
+        }
+    }
+    /// Returns `child`'s parent.
+    #[inline]
+    pub fn parent(&self, child: EntityId) -> Option<EntityId> {
+        self.parents.private_get(child).map(Parent::get)
+    }
+    /// Returns an iterator over `parent`'s direct children.
+    pub fn children(&self, parent: EntityId) -> ChildrenIter<'_> {
+        let (cursor, len) = self
+            .children
+            .private_get(parent)
+            .map(|children| (children.first, children.len))
+            .unwrap_or((EntityId::dead(), 0));
+
+        ChildrenIter {
+            parents: &self.parents,
+            cursor,
+            len,
+        }
+    }
+    /// Returns a depth-first iterator over all of `parent`'s descendants.
+    pub fn descendants(&self, parent: EntityId) -> DescendantsIter<'_> {
+        DescendantsIter {
+            children: &self.children,
+            stack: vec![self.children(parent)],
+        }
+    }
+    /// Returns an iterator over `child`'s parent, grandparent and so on.
+    pub fn ancestors(&self, child: EntityId) -> AncestorsIter<'_> {
+        AncestorsIter {
+            parents: &self.parents,
+            cursor: child,
+        }
+    }
+}
+
+/// Iterator over the direct children of an entity.
+pub struct ChildrenIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    cursor: EntityId,
+    len: usize,
+}
+
+impl Iterator for ChildrenIter<'_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let current = self.cursor;
+
+        // the link is dangling if `current` was deleted without being detached
+        let Some(parent) = self.parents.private_get(current) else {
+            self.len = 0;
+
+            return None;
+        };
+
+        self.len -= 1;
+        self.cursor = parent.next;
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len))
+    }
+}
+
+/// Depth-first iterator over the descendants of an entity.
+pub struct DescendantsIter<'a> {
+    children: &'a SparseSet<Children>,
+    stack: Vec<ChildrenIter<'a>>,
+}
+
+impl Iterator for DescendantsIter<'_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let level = self.stack.last_mut()?;
+
+            if let Some(entity) = level.next() {
+                if let Some(children) = self.children.private_get(entity) {
+                    let parents = level.parents;
+
+                    self.stack.push(ChildrenIter {
+                        parents,
+                        cursor: children.first,
+                        len: children.len,
+                    });
+                }
+
+                return Some(entity);
+            }
+
+            self.stack.pop();
+        }
+    }
+}
+
+/// Iterator over the ancestors of an entity, from the closest to the root.
+pub struct AncestorsIter<'a> {
+    parents: &'a SparseSet<Parent>,
+    cursor: EntityId,
+}
+
+impl Iterator for AncestorsIter<'_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = self.parents.private_get(self.cursor)?.parent;
+        self.cursor = parent;
+
+        Some(parent)
+    }
+}
+
+impl Borrow for HierarchyViewMut<'_> {
+    type View<'a> = HierarchyViewMut<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        Ok(HierarchyViewMut {
+            entities: EntitiesView::borrow(all_storages, all_borrow.clone(), last_run, current)?,
+            parents: ViewMut::<'a, Parent, track::Untracked>::borrow(
+                all_storages,
+                all_borrow.clone(),
+                last_run,
+                current,
+            )?,
+            children: ViewMut::<'a, Children, track::Untracked>::borrow(
+                all_storages,
+                all_borrow,
+                last_run,
+                current,
+            )?,
+        })
+    }
+}
+
+unsafe impl BorrowInfo for HierarchyViewMut<'_> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        EntitiesView::borrow_info(info);
+        ViewMut::<Parent, track::Untracked>::borrow_info(info);
+        ViewMut::<Children, track::Untracked>::borrow_info(info);
+    }
+
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+impl AllStorages {
+    /// Detaches `entity` from the hierarchy before it gets deleted.\
+    /// Does nothing if the hierarchy storages were never created.
+    pub(crate) fn unlink_hierarchy(&self, entity: EntityId) {
+        if !self.contains_storage(StorageId::of::<SparseSet<Parent>>()) {
+            return;
+        }
+
+        self.borrow::<HierarchyViewMut<'_>>()
+            .unwrap()
+            .unlink(entity);
+    }
+    /// Deletes `entity` and all its descendants.\
+    /// `entity` is detached from its parent first.
+    ///
+    /// Returns `false` if `entity` was not alive.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::hierarchy::HierarchyViewMut;
+    /// use shipyard::{AllStoragesViewMut, World};
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let [root, child, grandchild] = [(); 3].map(|_| all_storages.add_entity(()));
+    ///
+    /// {
+    ///     let mut hierarchy = all_storages.borrow::<HierarchyViewMut>().unwrap();
+    ///     hierarchy.attach_child(root, child);
+    ///     hierarchy.attach_child(child, grandchild);
+    /// }
+    ///
+    /// all_storages.delete_hierarchy(child);
+    ///
+    /// assert!(all_storages.is_entity_alive(root));
+    /// assert!(!all_storages.is_entity_alive(child));
+    /// assert!(!all_storages.is_entity_alive(grandchild));
+    /// ```
+    #[track_caller]
+    pub fn delete_hierarchy(&mut self, entity: EntityId) -> bool {
+        if !self.is_entity_alive(entity) {
+            return false;
+        }
+
+        let descendants = {
+            let mut hierarchy = self.borrow::<HierarchyViewMut<'_>>().unwrap();
+
+            hierarchy.detach(entity);
+            hierarchy.descendants(entity).collect::<Vec<_>>()
+        };
+
+        for descendant in descendants {
+            self.delete_entity(descendant);
+        }
+
+        self.delete_entity(entity)
+    }
+}
@@ -97,6 +97,8 @@ macro_rules! impl_shiperator_captain_mut {
                         flag: Some(&mut *self.modification_data.add(index)),
                         current: self.current,
                         data: &mut *self.data.add(index),
+                        compare: T::MODIFICATION_COMPARE,
+                        previous: None,
                     }
                 }
 
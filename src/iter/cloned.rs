@@ -0,0 +1,153 @@
+use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor};
+
+/// Turns borrowed components into owned components by cloning them.
+///
+/// Implemented for `&T` and tuples of types implementing `TupleCloned`.
+pub trait TupleCloned {
+    /// Owned version of the components.
+    type Cloned;
+
+    /// Clones each component.
+    fn tuple_cloned(self) -> Self::Cloned;
+}
+
+/// Turns borrowed components into owned components by copying them.
+///
+/// Implemented for `&T` and tuples of types implementing `TupleCopied`.
+pub trait TupleCopied {
+    /// Owned version of the components.
+    type Copied;
+
+    /// Copies each component.
+    fn tuple_copied(self) -> Self::Copied;
+}
+
+impl<T: Clone> TupleCloned for &T {
+    type Cloned = T;
+
+    #[inline]
+    fn tuple_cloned(self) -> Self::Cloned {
+        self.clone()
+    }
+}
+
+impl<T: Copy> TupleCopied for &T {
+    type Copied = T;
+
+    #[inline]
+    fn tuple_copied(self) -> Self::Copied {
+        *self
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> Shiperator<S>
+where
+    S::Out: TupleCloned,
+{
+    /// Clones the component(s) of each iteration.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Level(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Name("Alice".to_string()), Level(3)));
+    ///
+    /// world.run(|names: View<Name>, levels: View<Level>| {
+    ///     let players: Vec<(Name, Level)> = (&names, &levels).iter().cloned().collect();
+    ///
+    ///     assert_eq!(players, vec![(Name("Alice".to_string()), Level(3))]);
+    /// });
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn cloned(
+        self,
+    ) -> core::iter::Map<Shiperator<S>, fn(S::Out) -> <S::Out as TupleCloned>::Cloned> {
+        self.map(<S::Out as TupleCloned>::tuple_cloned)
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> Shiperator<S>
+where
+    S::Out: TupleCopied,
+{
+    /// Copies the component(s) of each iteration.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component, Clone, Copy, PartialEq, Debug)]
+    /// struct Vel(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Pos(0.0), Vel(1.0)));
+    ///
+    /// world.run(|positions: View<Pos>, velocities: View<Vel>| {
+    ///     let positions: Vec<Pos> = positions.iter().copied().collect();
+    ///     assert_eq!(positions, vec![Pos(0.0)]);
+    ///
+    ///     let velocities: Vec<(Vel,)> = (&velocities,).iter().copied().collect();
+    ///     assert_eq!(velocities, vec![(Vel(1.0),)]);
+    /// });
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn copied(
+        self,
+    ) -> core::iter::Map<Shiperator<S>, fn(S::Out) -> <S::Out as TupleCopied>::Copied> {
+        self.map(<S::Out as TupleCopied>::tuple_copied)
+    }
+}
+
+macro_rules! impl_cloned {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: TupleCloned),+> TupleCloned for ($($type,)+) {
+            type Cloned = ($($type::Cloned,)+);
+
+            #[inline]
+            fn tuple_cloned(self) -> Self::Cloned {
+                ($(self.$index.tuple_cloned(),)+)
+            }
+        }
+
+        impl<$($type: TupleCopied),+> TupleCopied for ($($type,)+) {
+            type Copied = ($($type::Copied,)+);
+
+            #[inline]
+            fn tuple_copied(self) -> Self::Copied {
+                ($(self.$index.tuple_copied(),)+)
+            }
+        }
+    }
+}
+
+macro_rules! cloned {
+    ($(($type: ident, $index: tt))+; ($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_cloned![$(($type, $index))*];
+        cloned![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))+;) => {
+        impl_cloned![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+cloned![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+cloned![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
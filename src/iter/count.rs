@@ -0,0 +1,45 @@
+use crate::iter::{IntoIter, IntoShiperator, ShiperatorCaptain, ShiperatorSailor};
+
+/// Trait used to count the entities matching a query without iterating the components.
+pub trait Count: IntoShiperator {
+    /// Returns the number of entities matching the query.
+    ///
+    /// The smallest storage is walked and the others are probed, components are never read.\
+    /// When a single storage is queried, its length is returned directly.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Count, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Vel(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Pos(0.0), Vel(1.0)));
+    /// world.add_entity(Pos(1.0));
+    /// world.add_entity((Pos(2.0), Vel(0.0)));
+    ///
+    /// world.run(|positions: View<Pos>, velocities: View<Vel>| {
+    ///     let count = (&positions, &velocities).count();
+    ///     assert_eq!(count, 2);
+    ///
+    ///     let mut buffer = Vec::with_capacity(count);
+    ///     buffer.extend((&positions, &velocities).iter().map(|(pos, vel)| pos.0 + vel.0));
+    /// });
+    /// ```
+    fn count(self) -> usize;
+}
+
+impl<T: IntoShiperator> Count for T
+where
+    T::Shiperator: ShiperatorCaptain + ShiperatorSailor,
+{
+    #[inline]
+    fn count(self) -> usize {
+        Iterator::count(self.iter())
+    }
+}
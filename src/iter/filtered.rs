@@ -0,0 +1,113 @@
+use crate::entity_id::EntityId;
+use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor, WithId};
+use core::iter::FusedIterator;
+
+/// Iterator yielding the components matching a predicate.
+pub struct Filtered<S, F> {
+    shiperator: Shiperator<S>,
+    predicate: F,
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> Shiperator<S> {
+    /// Only yields the components for which `predicate` returns `true`.\
+    /// `predicate` receives a shared reference to the item, reading through [`Mut`](crate::Mut) doesn't flag components as modified.
+    ///
+    /// Unlike [`Iterator::filter`], the result can still use [`with_id`](Filtered::with_id).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Active(bool);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity((Active(true), U32(0)));
+    /// world.add_entity((Active(false), U32(1)));
+    ///
+    /// world.run(|actives: View<Active>, mut u32s: ViewMut<U32>| {
+    ///     for (entity, (_, mut u)) in (&actives, &mut u32s)
+    ///         .iter()
+    ///         .filtered(|(active, _)| active.0)
+    ///         .with_id()
+    ///     {
+    ///         assert_eq!(entity, entity0);
+    ///         u.0 += 10;
+    ///     }
+    /// });
+    /// ```
+    pub fn filtered<F: FnMut(&S::Out) -> bool>(self, predicate: F) -> Filtered<S, F> {
+        Filtered {
+            shiperator: self,
+            predicate,
+        }
+    }
+}
+
+impl<S, F> Filtered<S, F> {
+    /// Returns the [`EntityId`] alongside the component(s).
+    pub fn with_id(self) -> WithId<Filtered<S, F>> {
+        WithId(self)
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor, F: FnMut(&S::Out) -> bool> Iterator
+    for Filtered<S, F>
+{
+    type Item = S::Out;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.shiperator.next()?;
+
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.shiperator.size_hint().1)
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor, F: FnMut(&S::Out) -> bool> FusedIterator
+    for Filtered<S, F>
+{
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor, F: FnMut(&S::Out) -> bool> Iterator
+    for WithId<Filtered<S, F>>
+{
+    type Item = (EntityId, S::Out);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.0.shiperator.next()?;
+
+            if (self.0.predicate)(&item) {
+                let shiperator = &self.0.shiperator;
+                let entity_id = unsafe { shiperator.entities.get(shiperator.start - 1) };
+
+                return Some((entity_id, item));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor, F: FnMut(&S::Out) -> bool> FusedIterator
+    for WithId<Filtered<S, F>>
+{
+}
@@ -43,6 +43,11 @@ pub trait IntoIter: IntoShiperator {
     /// });
     /// ```
     fn iter(self) -> Shiperator<Self::Shiperator>;
+    /// Iterates in parallel, the work is split on the smallest storage like [`iter`](IntoIter::iter).
+    ///
+    /// Components borrowed with a shared view have to be `Sync` and components borrowed with an exclusive view have to be `Send`,
+    /// otherwise the returned iterator can't be used as a `ParallelIterator`.
+    ///
     /// ### Example
     /// ```
     /// use rayon::prelude::ParallelIterator;
@@ -1,23 +1,33 @@
 mod captain;
+mod cloned;
+mod count;
+mod filtered;
 mod into_shiperator;
 mod mixed;
+mod ordered_by_id;
 mod output;
 #[cfg(feature = "parallel")]
 mod parallel;
 mod sailor;
+mod with_age;
 mod with_id;
 
 #[doc(inline)]
 pub use crate::sparse_set::RawEntityIdAccess;
 pub use captain::ShiperatorCaptain;
+pub use cloned::{TupleCloned, TupleCopied};
+pub use count::Count;
+pub use filtered::Filtered;
 pub use into_shiperator::{IntoIter, IntoShiperator};
 pub use mixed::Mixed;
+pub use ordered_by_id::OrderedById;
 pub use output::ShiperatorOutput;
 #[cfg(feature = "parallel")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
 pub use parallel::ParShiperator;
 pub use sailor::ShiperatorSailor;
-pub use with_id::WithId;
+pub use with_age::WithAge;
+pub use with_id::{WithId, WithIdAndDenseIndex};
 
 use crate::component::Component;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut};
@@ -75,6 +85,39 @@ impl<S: ShiperatorCaptain + ShiperatorSailor> Iterator for Shiperator<S> {
         }
     }
 
+    #[inline]
+    fn count(mut self) -> usize {
+        let mut count = 0;
+
+        loop {
+            if self.start == self.end {
+                if let Some(new_end) = self.entities.next_slice() {
+                    self.start = 0;
+                    self.end = new_end;
+
+                    self.shiperator.next_slice();
+                } else {
+                    return count;
+                }
+            };
+
+            if self.is_exact_sized {
+                count += self.end - self.start;
+                self.start = self.end;
+            } else {
+                while self.start < self.end {
+                    let current = self.start;
+                    self.start += 1;
+                    let entity_id = unsafe { self.entities.get(current) };
+
+                    if self.shiperator.indices_of(entity_id, current).is_some() {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+
     #[inline]
     fn fold<B, F>(mut self, mut init: B, mut f: F) -> B
     where
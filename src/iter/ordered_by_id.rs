@@ -0,0 +1,95 @@
+use crate::entity_id::EntityId;
+use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor, WithId};
+use alloc::vec::{IntoIter, Vec};
+
+/// Iterator yielding components in ascending [`EntityId`] index order.
+pub struct OrderedById<T>(IntoIter<(EntityId, T)>);
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> Shiperator<S> {
+    /// Yields the components in ascending [`EntityId`] index order instead of storage order.\
+    /// The order only depends on which entities match, not on the history of insertions and removals.
+    ///
+    /// All matching components are collected and sorted before the first item is returned.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(());
+    /// let entity1 = world.add_entity(U32(1));
+    /// world.add_component(entity0, U32(0));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     let ordered = (&mut u32s)
+    ///         .iter()
+    ///         .ordered_by_id()
+    ///         .with_id()
+    ///         .map(|(entity, u)| (entity, u.0))
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(ordered, vec![(entity0, 0), (entity1, 1)]);
+    /// });
+    /// ```
+    pub fn ordered_by_id(self) -> OrderedById<S::Out> {
+        let mut items = self.with_id().collect::<Vec<_>>();
+        items.sort_unstable_by_key(|(entity, _)| entity.index());
+
+        OrderedById(items.into_iter())
+    }
+}
+
+impl<T> OrderedById<T> {
+    /// Returns the [`EntityId`] alongside the component(s).
+    pub fn with_id(self) -> WithId<Self> {
+        WithId(self)
+    }
+}
+
+impl<T> Iterator for OrderedById<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, item)| item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for OrderedById<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, item)| item)
+    }
+}
+
+impl<T> ExactSizeIterator for OrderedById<T> {}
+
+impl<T> Iterator for WithId<OrderedById<T>> {
+    type Item = (EntityId, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 .0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0 .0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for WithId<OrderedById<T>> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0 .0.next_back()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::component::Component;
+use crate::iter::Shiperator;
+use crate::sparse_set::FullRawWindow;
+use crate::tracking::TrackingTimestamp;
+use core::iter::FusedIterator;
+
+/// Iterator that returns the age of the component alongside it.
+///
+/// The age is the number of tracking cycles since the component was last modified.\
+/// Components that were never modified since their insertion use their insertion timestamp instead.
+pub struct WithAge<S> {
+    pub(crate) shiperator: S,
+    pub(crate) current: TrackingTimestamp,
+}
+
+impl<'tmp, T: Component> Shiperator<FullRawWindow<'tmp, T>> {
+    /// Returns how many tracking cycles ago each component was last modified alongside it.\
+    /// `current` is usually [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp).
+    ///
+    /// A component that was never modified since its insertion is aged from its insertion timestamp.
+    /// If the storage doesn't track insertion, its age is counted from the origin timestamp.
+    ///
+    /// ### Panics
+    ///
+    /// - The storage doesn't track modification.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion, Modification)]
+    /// struct Flash(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Flash(0));
+    ///
+    /// // the insertion happened one tracking cycle ago
+    /// let current = world.get_tracking_timestamp();
+    /// world.run(|flashes: View<Flash>| {
+    ///     for (flash, age) in flashes.iter().with_age(current) {
+    ///         assert_eq!(flash.0, 0);
+    ///         assert_eq!(age, 1);
+    ///     }
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn with_age(self, current: TrackingTimestamp) -> WithAge<Self> {
+        assert!(
+            self.shiperator.is_tracking_modification,
+            "{} does not track modification. Add `#[track(Modification)]` to the component or call `track_modification` on its storage.",
+            core::any::type_name::<T>()
+        );
+
+        WithAge {
+            shiperator: self,
+            current,
+        }
+    }
+}
+
+impl<'tmp, T: Component> WithAge<Shiperator<FullRawWindow<'tmp, T>>> {
+    /// Returns the age of the component at `index` in the dense vector.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bound of the window.
+    #[inline]
+    unsafe fn age_at(&self, index: usize) -> u64 {
+        let window = &self.shiperator.shiperator;
+
+        let mut timestamp = *window.modification_data.add(index);
+        if timestamp.get() == TrackingTimestamp::origin().get() && window.is_tracking_insertion {
+            timestamp = *window.insertion_data.add(index);
+        }
+
+        self.current.get().saturating_sub(timestamp.get())
+    }
+}
+
+impl<'tmp, T: Component> Iterator for WithAge<Shiperator<FullRawWindow<'tmp, T>>> {
+    type Item = (&'tmp T, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let component = self.shiperator.next()?;
+        let age = unsafe { self.age_at(self.shiperator.start - 1) };
+
+        Some((component, age))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.shiperator.size_hint()
+    }
+}
+
+impl<'tmp, T: Component> DoubleEndedIterator for WithAge<Shiperator<FullRawWindow<'tmp, T>>> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let component = self.shiperator.next_back()?;
+        let age = unsafe { self.age_at(self.shiperator.end) };
+
+        Some((component, age))
+    }
+}
+
+impl<'tmp, T: Component> ExactSizeIterator for WithAge<Shiperator<FullRawWindow<'tmp, T>>> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.shiperator.len()
+    }
+}
+
+impl<'tmp, T: Component> FusedIterator for WithAge<Shiperator<FullRawWindow<'tmp, T>>> {}
@@ -1,5 +1,7 @@
+use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::iter::{Shiperator, ShiperatorCaptain, ShiperatorSailor};
+use crate::sparse_set::{FullRawWindow, FullRawWindowMut};
 use core::iter::FusedIterator;
 
 /// Iterator that returns the [`EntityId`] alongside the component(s).
@@ -164,3 +166,90 @@ impl<S: ShiperatorCaptain + ShiperatorSailor + Send + Clone>
         folder.consume_iter(self)
     }
 }
+
+/// Iterator that returns the [`EntityId`] and the index in the storage's dense vector alongside the component.
+pub struct WithIdAndDenseIndex<S>(pub(crate) S);
+
+impl<'tmp, T: Component> Shiperator<FullRawWindow<'tmp, T>> {
+    /// Returns the [`EntityId`] and the index in the storage's dense vector alongside the component.
+    ///
+    /// The dense index is only valid until the next insertion, removal or deletion in this storage.\
+    /// It is the same index [`SparseSet::index_of`](crate::sparse_set::SparseSet::index_of) returns.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Instance(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Instance(0));
+    /// world.add_entity(Instance(1));
+    ///
+    /// world.run(|instances: View<Instance>| {
+    ///     for (entity, index, instance) in instances.iter().with_id_and_dense_index() {
+    ///         assert_eq!(instances.index_of(entity), Some(index));
+    ///         assert_eq!(instance.0 as usize, index);
+    ///     }
+    /// });
+    /// ```
+    pub fn with_id_and_dense_index(self) -> WithIdAndDenseIndex<Self> {
+        WithIdAndDenseIndex(self)
+    }
+}
+
+impl<'tmp, T: Component, Track> Shiperator<FullRawWindowMut<'tmp, T, Track>> {
+    /// Returns the [`EntityId`] and the index in the storage's dense vector alongside the component.
+    ///
+    /// The dense index is only valid until the next insertion, removal or deletion in this storage.\
+    /// It is the same index [`SparseSet::index_of`](crate::sparse_set::SparseSet::index_of) returns.
+    pub fn with_id_and_dense_index(self) -> WithIdAndDenseIndex<Self> {
+        WithIdAndDenseIndex(self)
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> Iterator for WithIdAndDenseIndex<Shiperator<S>> {
+    type Item = (EntityId, usize, S::Out);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.0.next()?;
+        let index = self.0.start - 1;
+        let entity_id = unsafe { self.0.entities.get(index) };
+
+        Some((entity_id, index, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> DoubleEndedIterator
+    for WithIdAndDenseIndex<Shiperator<S>>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.0.next_back()?;
+        let index = self.0.end;
+        let entity_id = unsafe { self.0.entities.get(index) };
+
+        Some((entity_id, index, item))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithIdAndDenseIndex<I>
+where
+    WithIdAndDenseIndex<I>: Iterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<S: ShiperatorCaptain + ShiperatorSailor> FusedIterator for WithIdAndDenseIndex<Shiperator<S>> {}
@@ -5,8 +5,12 @@
 //! ## Features
 //!
 //! - **parallel** *(default)* &mdash; enables workload threading and add parallel iterators
+//! - **entity_tag** &mdash; reserves 8 bits of [`EntityId`] to tag entities with the [`World`] that generated them.
+//!   This changes [`EntityId`]'s bit layout, indices are limited to 40 bits instead of 48 and [`EntityId::inner`] of a tagged id is not valid without the feature
 //! - **extended_tuple** &mdash; extends implementations from the default 10 to 32 tuple size at the cost of 4X build time
+//! - **hierarchy** &mdash; adds `Parent` and `Children` components to build entity hierarchies
 //! - **proc** *(default)* &mdash; re-exports macros from `shipyard_proc`, mainly to derive `Component`
+//! - **schedule_trace** &mdash; records which thread ran each system and in what order during workloads
 //! - **serde1** &mdash; adds (de)serialization support with [serde](https://github.com/serde-rs/serde)
 //! - **std** *(default)* &mdash; lets Shipyard use the standard library
 //! - **thread_local** &mdash; adds methods and types required to work with `!Send` and `!Sync` components
@@ -44,21 +48,31 @@ mod add_distinct_component;
 mod add_entity;
 #[allow(missing_docs)]
 pub mod all_storages;
+/// Per system allocation statistics.
+#[cfg(feature = "alloc_stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc_stats")))]
+pub mod alloc_stats;
 /// Inner lock similar to `RwLock`.
 pub mod atomic_refcell;
 /// Allows access to helper types needed to implement [`Borrow`](borrow::Borrow).
 pub mod borrow;
+#[cfg(feature = "std")]
+mod commands;
 mod component;
 mod contains;
 mod delete;
 mod entities;
 mod entity_id;
 pub mod error;
+mod events;
 mod get;
 /// Trait bound for [`AllStorages::get`] and [`World::get`].
 pub mod get_component;
 /// Trait bound for [`AllStorages::get_unique`] and [`World::get_unique`].
 pub mod get_unique;
+#[cfg(feature = "hierarchy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hierarchy")))]
+pub mod hierarchy;
 #[allow(clippy::empty_docs)]
 ///
 // We can't allow(missing_docs) without allowing it for everything inside
@@ -75,6 +89,10 @@ mod public_transport;
 mod remove;
 #[allow(missing_docs)]
 pub mod reserve;
+/// Record of the parallel execution of workloads.
+#[cfg(feature = "schedule_trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schedule_trace")))]
+pub mod schedule_trace;
 #[allow(missing_docs)]
 pub mod scheduler;
 mod seal;
@@ -107,14 +125,19 @@ pub use add_distinct_component::AddDistinctComponent;
 pub use add_entity::AddEntity;
 #[doc(inline)]
 pub use all_storages::AllStorages;
+#[cfg(feature = "std")]
+pub use all_storages::ExtractedSubset;
+#[cfg(feature = "std")]
+pub use commands::CommandBufferViewMut;
 pub use component::{Component, Unique};
-pub use contains::Contains;
+pub use contains::{Contains, ContainsChecker, IntoContainsChecker};
 pub use delete::Delete;
 pub use entities::Entities;
-pub use entity_id::EntityId;
+pub use entity_id::{EntityId, NamedEntityId};
+pub use events::{EventReader, EventWriter, Events};
 pub use get::Get;
 #[doc(inline)]
-pub use iter::IntoIter;
+pub use iter::{Count, IntoIter};
 pub use not::Not;
 pub use optional::Optional;
 pub use or::{OneOfTwo, Or};
@@ -122,23 +145,28 @@ pub use r#mut::Mut;
 pub use remove::Remove;
 #[doc(inline)]
 pub use scheduler::{
-    IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, SystemModificator, Workload,
-    WorkloadModificator,
+    ErrorResolution, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, SystemHooks,
+    SystemModificator, Workload, WorkloadModificator,
 };
 #[cfg(feature = "proc")]
 pub use shipyard_proc::{Borrow, BorrowInfo, Component, IntoIter, Label, Unique, WorldBorrow};
 pub use storage::{Storage, StorageId};
 #[doc(inline)]
-pub use tracking::{Inserted, InsertedOrModified, Modified};
+pub use tracking::{Inserted, InsertedOrModified, ModificationCompare, Modified};
 pub use unique::UniqueStorage;
 pub use views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueOrDefaultView,
-    UniqueOrDefaultViewMut, UniqueOrInitView, UniqueOrInitViewMut, UniqueView, UniqueViewMut, View,
-    ViewMut,
+    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, Entry, OccupiedEntry,
+    UniqueOrDefaultView, UniqueOrDefaultViewMut, UniqueOrInitView, UniqueOrInitViewMut, UniqueView,
+    UniqueViewMut, VacantEntry, View, ViewMut,
 };
 #[doc(inline)]
 pub use world::World;
 
+#[cfg(not(feature = "std"))]
+type ShipBuildHasher = core::hash::BuildHasherDefault<siphasher::sip::SipHasher>;
+#[cfg(feature = "std")]
+type ShipBuildHasher = hashbrown::hash_map::DefaultHashBuilder;
+
 #[cfg(not(feature = "std"))]
 type ShipHashMap<K, V> =
     hashbrown::HashMap<K, V, core::hash::BuildHasherDefault<siphasher::sip::SipHasher>>;
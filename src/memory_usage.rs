@@ -34,6 +34,28 @@ impl core::fmt::Debug for StorageMemoryUsage {
     }
 }
 
+/// Memory usage of a storage, returned by [`AllStorages::memory_report`].
+pub struct StorageMemoryReport {
+    /// Totals of the storage.
+    pub usage: StorageMemoryUsage,
+    /// Bytes used by each part of the storage, `None` if the storage doesn't report it.
+    pub used: Option<SparseSetMemory>,
+    /// Bytes allocated by each part of the storage, `None` if the storage doesn't report it.
+    pub allocated: Option<SparseSetMemory>,
+}
+
+impl core::fmt::Debug for StorageMemoryReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (&self.used, &self.allocated) {
+            (Some(used), Some(allocated)) => f.write_fmt(format_args!(
+                "{:?}\n - used: {:?}\n - reserved: {:?}",
+                self.usage, used, allocated,
+            )),
+            _ => core::fmt::Debug::fmt(&self.usage, f),
+        }
+    }
+}
+
 /// A trait to query the detailed memory usage of a storage
 pub trait MemoryUsageDetail {
     /// The output type of the detailed memory usage.
@@ -1,25 +1,74 @@
-use crate::tracking::TrackingTimestamp;
+use crate::tracking::{ModificationCompare, TrackingTimestamp};
+use alloc::boxed::Box;
+use core::mem::ManuallyDrop;
 
 /// Tracks component modification.
 pub struct Mut<'a, T: ?Sized> {
     pub(crate) flag: Option<&'a mut TrackingTimestamp>,
     pub(crate) current: TrackingTimestamp,
     pub(crate) data: &'a mut T,
+    /// Set for components only flagged when their value changed, see [`ModificationCompare`].
+    pub(crate) compare: Option<ModificationCompare<T>>,
+    /// Value before the first mutable access, compared on drop.
+    pub(crate) previous: Option<Box<T>>,
 }
 
 impl<'a, T: ?Sized> Mut<'a, T> {
     /// Makes a new [`Mut`], the component will not be flagged if its modified inside `f`.
     ///
+    /// If the component compares its value and was already accessed mutably, the comparison happens before `f` is called.
+    /// The returned [`Mut`] flags any mutable access.
+    ///
     /// This is an associated function that needs to be used as `Mut::map(...)`. A method would interfere with methods of the same name used through Deref.
     pub fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(orig: Self, f: F) -> Mut<'a, U> {
+        let mut orig = ManuallyDrop::new(orig);
+        orig.compare_previous();
+
+        // SAFE orig is never used or dropped after this point and previous is empty
+        let (flag, data) = unsafe { (core::ptr::read(&orig.flag), core::ptr::read(&orig.data)) };
+
         Mut {
-            flag: orig.flag,
+            flag,
             current: orig.current,
-            data: f(orig.data),
+            data: f(data),
+            compare: None,
+            previous: None,
+        }
+    }
+
+    #[inline]
+    fn flag_modified(&mut self) {
+        if let Some(flag) = &mut self.flag {
+            match self.compare {
+                Some(compare) => {
+                    if self.previous.is_none() {
+                        self.previous = Some((compare.clone)(self.data));
+                    }
+                }
+                None => **flag = self.current,
+            }
+        }
+    }
+
+    #[inline]
+    fn compare_previous(&mut self) {
+        if let (Some(flag), Some(compare), Some(previous)) =
+            (&mut self.flag, self.compare, self.previous.take())
+        {
+            if !(compare.eq)(&previous, self.data) {
+                **flag = self.current;
+            }
         }
     }
 }
 
+impl<T: ?Sized> Drop for Mut<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.compare_previous();
+    }
+}
+
 impl<T: ?Sized> core::ops::Deref for Mut<'_, T> {
     type Target = T;
 
@@ -32,9 +81,7 @@ impl<T: ?Sized> core::ops::Deref for Mut<'_, T> {
 impl<T: ?Sized> core::ops::DerefMut for Mut<'_, T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        if let Some(flag) = &mut self.flag {
-            **flag = self.current;
-        }
+        self.flag_modified();
 
         self.data
     }
@@ -50,9 +97,7 @@ impl<T: ?Sized> AsRef<T> for Mut<'_, T> {
 impl<T: ?Sized> AsMut<T> for Mut<'_, T> {
     #[inline]
     fn as_mut(&mut self) -> &mut T {
-        if let Some(flag) = &mut self.flag {
-            **flag = self.current;
-        }
+        self.flag_modified();
 
         self.data
     }
@@ -0,0 +1,65 @@
+use crate::scheduler::Label;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Execution record of a system during a workload run.
+///
+/// `start` and `end` are positions in a single sequence of events shared by all systems of the run.\
+/// Two systems ran in parallel when each one started before the other ended.
+#[derive(Clone, Debug)]
+pub struct ScheduledSystem {
+    /// Name of the system.
+    pub name: Box<dyn Label>,
+    /// Index of the rayon thread that ran the system, `None` when it ran outside of rayon's thread pool.
+    pub thread: Option<usize>,
+    /// Position of the system's start event.
+    pub start: usize,
+    /// Position of the system's end event.
+    pub end: usize,
+}
+
+impl ScheduledSystem {
+    /// Returns `true` if `self` and `other` overlapped in time.
+    pub fn overlaps(&self, other: &ScheduledSystem) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+pub(crate) struct ScheduleTrace {
+    next_event: AtomicUsize,
+    systems: Mutex<Vec<ScheduledSystem>>,
+}
+
+impl ScheduleTrace {
+    pub(crate) fn new() -> ScheduleTrace {
+        ScheduleTrace {
+            next_event: AtomicUsize::new(0),
+            systems: Mutex::new(Vec::new()),
+        }
+    }
+    pub(crate) fn clear(&self) {
+        self.next_event.store(0, Ordering::Relaxed);
+        self.systems.lock().unwrap().clear();
+    }
+    pub(crate) fn start(&self) -> usize {
+        self.next_event.fetch_add(1, Ordering::AcqRel)
+    }
+    pub(crate) fn end(&self, name: Box<dyn Label>, start: usize) {
+        let end = self.next_event.fetch_add(1, Ordering::AcqRel);
+
+        self.systems.lock().unwrap().push(ScheduledSystem {
+            name,
+            thread: rayon::current_thread_index(),
+            start,
+            end,
+        });
+    }
+    pub(crate) fn systems(&self) -> Vec<ScheduledSystem> {
+        let mut systems = self.systems.lock().unwrap().clone();
+        systems.sort_unstable_by_key(|system| system.start);
+
+        systems
+    }
+}
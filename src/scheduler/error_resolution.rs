@@ -0,0 +1,17 @@
+use crate::error;
+use crate::scheduler::Label;
+use alloc::boxed::Box;
+
+/// Decides what happens to a workload when one of its systems returns an error, see [`Workload::on_error`].
+///
+/// [`Workload::on_error`]: crate::Workload::on_error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorResolution {
+    /// Ignores the error and keeps running the workload.
+    Skip,
+    /// Stops the workload and returns the error.
+    Abort,
+}
+
+pub(crate) type ErrorHandler =
+    Box<dyn Fn(&dyn Label, &error::Run) -> ErrorResolution + Send + Sync>;
@@ -10,6 +10,7 @@ use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Write;
 use core::hash::BuildHasherDefault;
 
 /// Contains information related to a workload.
@@ -24,6 +25,82 @@ pub struct WorkloadInfo {
     pub batch_info: Vec<BatchInfo>,
 }
 
+impl WorkloadInfo {
+    /// Returns a [DOT](https://graphviz.org/doc/info/lang.html) graph of this workload.
+    ///
+    /// Each batch is a cluster containing its systems.\
+    /// When a system couldn't join an earlier batch, an edge goes from the system it conflicted with to it, labeled with the conflicting type.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let mut nodes: Vec<(&TypeId, String)> = Vec::new();
+
+        let _ = writeln!(dot, "digraph \"{}\" {{", escape_dot(&self.name));
+
+        for (batch_index, batch) in self.batch_info.iter().enumerate() {
+            let _ = writeln!(dot, "    subgraph cluster_{} {{", batch_index);
+            let _ = writeln!(dot, "        label = \"batch {}\";", batch_index);
+
+            for (system_index, system) in batch.systems().enumerate() {
+                let node = format!("b{}_{}", batch_index, system_index);
+
+                let _ = writeln!(
+                    dot,
+                    "        {} [label=\"{}\"];",
+                    node,
+                    escape_dot(&system.name)
+                );
+
+                nodes.push((&system.type_id, node));
+            }
+
+            let _ = writeln!(dot, "    }}");
+        }
+
+        let mut node_index = 0;
+        for batch in &self.batch_info {
+            for system in batch.systems() {
+                let (other_system, type_info) = match &system.conflict {
+                    Some(Conflict::Borrow {
+                        other_system,
+                        other_type_info,
+                        ..
+                    }) => (other_system, other_type_info),
+                    Some(Conflict::OtherNotSendSync { system, type_info }) => (system, type_info),
+                    Some(Conflict::NotSendSync(_)) | None => {
+                        node_index += 1;
+                        continue;
+                    }
+                };
+
+                let other_node = nodes[..node_index]
+                    .iter()
+                    .rev()
+                    .find(|(type_id, _)| **type_id == other_system.type_id);
+
+                if let Some((_, other_node)) = other_node {
+                    let _ = writeln!(
+                        dot,
+                        "    {} -> {} [label=\"{}\"];",
+                        other_node,
+                        nodes[node_index].1,
+                        escape_dot(&type_info.name)
+                    );
+                }
+
+                node_index += 1;
+            }
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+fn escape_dot(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 /// Contains information related to a batch.
 ///
 /// A batch is a collection of system that can safely run in parallel.
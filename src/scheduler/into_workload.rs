@@ -189,6 +189,10 @@ where
                 require_before: DedupedLabels::new(),
                 require_after: DedupedLabels::new(),
                 barriers: Vec::new(),
+                repeated: Vec::new(),
+                grouped: false,
+                max_threads: None,
+                on_error: None,
             }
         }
     }
@@ -231,6 +235,10 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    repeated: Vec::new(),
+                    grouped: false,
+                    max_threads: None,
+                    on_error: None,
                 };
 
                 $(
@@ -261,6 +269,10 @@ macro_rules! impl_into_workload {
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    repeated: Vec::new(),
+                    grouped: false,
+                    max_threads: None,
+                    on_error: None,
                 };
 
                 let mut sequential_tags = Vec::new();
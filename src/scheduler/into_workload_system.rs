@@ -58,6 +58,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            conditions: Vec::new(),
         })
     }
     fn label(&self) -> Box<dyn Label> {
@@ -173,6 +175,8 @@ macro_rules! impl_into_workload_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    conditions: Vec::new(),
                 })
             }
             fn label(&self) -> Box<dyn Label> {
@@ -72,6 +72,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            conditions: Vec::new(),
         })
     }
     #[cfg(not(feature = "std"))]
@@ -103,6 +105,8 @@ where
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
+            priority: 0,
+            conditions: Vec::new(),
         })
     }
 }
@@ -207,6 +211,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    conditions: Vec::new(),
                 })
             }
             #[cfg(not(feature = "std"))]
@@ -278,6 +284,8 @@ macro_rules! impl_into_workload_try_system {
                     require_in_workload: DedupedLabels::new(),
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
+                    priority: 0,
+                    conditions: Vec::new(),
                 })
             }
         }
@@ -6,6 +6,7 @@ use alloc::string::String;
 use core::any::Any;
 use core::fmt::{Debug, Formatter};
 use core::hash::{Hash, Hasher};
+use core::sync::atomic::{AtomicU64, Ordering};
 
 /// Workload identifier
 pub trait Label: 'static + Send + Sync {
@@ -236,3 +237,41 @@ impl Label for WorkloadLabel {
         f.write_fmt(format_args!("Workload({:?})", self.name))
     }
 }
+
+/// Labels the systems generated by [`Workload::branch`](crate::Workload::branch).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(crate) struct BranchLabel(pub(crate) u64);
+
+impl BranchLabel {
+    pub(crate) fn new() -> BranchLabel {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        BranchLabel(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Label for BranchLabel {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Label) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<BranchLabel>() {
+            self == other
+        } else {
+            false
+        }
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        BranchLabel::hash(self, &mut state)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn Label> {
+        Box::new(*self)
+    }
+
+    fn dyn_debug(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!("Branch({})", self.0))
+    }
+}
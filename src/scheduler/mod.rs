@@ -1,3 +1,4 @@
+mod error_resolution;
 pub mod info;
 mod into_workload;
 mod into_workload_run_if;
@@ -5,27 +6,35 @@ mod into_workload_system;
 mod into_workload_try_system;
 mod label;
 mod system;
+mod system_hooks;
 mod system_modificator;
 mod workload;
 mod workload_modificator;
 
+pub use error_resolution::ErrorResolution;
 pub use into_workload::IntoWorkload;
 pub use into_workload_system::IntoWorkloadSystem;
 pub use into_workload_try_system::IntoWorkloadTrySystem;
 pub use label::{AsLabel, Label};
 pub use system::WorkloadSystem;
+pub use system_hooks::SystemHooks;
 pub use system_modificator::SystemModificator;
 pub use workload::{ScheduledWorkload, Workload};
 pub use workload_modificator::WorkloadModificator;
 
+pub(crate) use error_resolution::ErrorHandler;
 pub(crate) use info::TypeInfo;
 
 use crate::scheduler::info::WorkloadInfo;
+use crate::scheduler::label::SystemLabel;
 use crate::scheduler::system::WorkloadRunIfFn;
 use crate::type_id::TypeId;
 use crate::world::World;
 use crate::{error, ShipHashMap};
 use alloc::boxed::Box;
+use alloc::sync::Arc;
+// macro not module
+use alloc::vec;
 use alloc::vec::Vec;
 use core::hash::BuildHasherDefault;
 
@@ -41,7 +50,29 @@ pub(super) struct Batches {
     pub(super) sequential: Vec<usize>,
     pub(super) sequential_run_if:
         Vec<Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>>,
+    /// Index into `conditions` and the result required for each sequential system to run
+    pub(super) sequential_conditions: Vec<Vec<(usize, bool)>>,
+    /// Conditions shared by multiple systems, evaluated at most once per run
+    pub(super) conditions: Vec<Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
     pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+    /// Maximum number of systems running at the same time
+    pub(super) max_threads: Option<usize>,
+    pub(super) on_error: Option<ErrorHandler>,
+    /// Workloads repeated in the middle of this one
+    pub(super) repeated: Vec<RepeatedBatches>,
+}
+
+/// Workload running multiple times in a row inside another workload
+pub(super) struct RepeatedBatches {
+    /// The workload runs before this parallel batch
+    pub(super) parallel_position: usize,
+    /// The workload runs before this sequential system
+    pub(super) sequential_position: usize,
+    pub(super) name: Box<dyn Label>,
+    pub(super) batches: Batches,
+    pub(super) count: Box<dyn Fn(&World) -> usize + Send + Sync>,
+    /// Index into the parent's `conditions` and the result required for the workload to run
+    pub(super) conditions: Vec<(usize, bool)>,
 }
 
 #[cfg(test)]
@@ -125,6 +156,58 @@ impl Scheduler {
     pub(crate) fn is_empty(&self) -> bool {
         self.workloads.is_empty()
     }
+    /// Returns the labels of `name`'s systems in execution order.
+    pub(crate) fn system_labels(&self, name: &dyn Label) -> Option<Vec<Box<dyn Label>>> {
+        let batches = self.workloads.get(name)?;
+
+        let mut type_ids = vec![None; self.system_names.len()];
+        for (&type_id, &index) in &self.lookup_table {
+            type_ids[index] = Some(type_id);
+        }
+
+        let mut labels = Vec::new();
+        self.collect_system_labels(batches, &type_ids, &mut labels);
+
+        Some(labels)
+    }
+    fn collect_system_labels(
+        &self,
+        batches: &Batches,
+        type_ids: &[Option<TypeId>],
+        labels: &mut Vec<Box<dyn Label>>,
+    ) {
+        let mut repeated = batches.repeated.iter().peekable();
+
+        for (position, &index) in batches.sequential.iter().enumerate() {
+            while let Some(segment) =
+                repeated.next_if(|segment| segment.sequential_position == position)
+            {
+                self.collect_system_labels(&segment.batches, type_ids, labels);
+            }
+
+            labels.push(match type_ids[index] {
+                Some(type_id) => Box::new(SystemLabel {
+                    type_id,
+                    name: self.system_names[index].clone(),
+                }) as Box<dyn Label>,
+                None => self.system_names[index].clone(),
+            });
+        }
+
+        for segment in repeated {
+            self.collect_system_labels(&segment.batches, type_ids, labels);
+        }
+    }
+    /// Removes `name` workload, its systems stay registered.
+    pub(crate) fn remove_workload(
+        &mut self,
+        name: &dyn Label,
+    ) -> Option<(Box<dyn Label>, Batches, Option<WorkloadInfo>)> {
+        let (label, batches) = self.workloads.remove_entry(name)?;
+        let info = self.workloads_info.remove(name);
+
+        Some((label, batches, info))
+    }
     pub(crate) fn rename(&mut self, old: &dyn Label, new: Box<dyn Label>) {
         if let Some(batches) = self.workloads.remove(old) {
             if &*self.default == old {
@@ -7,6 +7,7 @@ use crate::scheduler::workload::Workload;
 use crate::type_id::TypeId;
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 /// Self contained system that may be inserted into a [`Workload`].
@@ -61,6 +62,14 @@ pub struct WorkloadSystem {
     pub require_before: DedupedLabels,
     #[allow(missing_docs)]
     pub require_after: DedupedLabels,
+    /// Systems with a higher priority are dispatched first within a parallel batch.
+    pub priority: i32,
+    /// Conditions shared with other systems, evaluated a single time per workload run.\
+    /// The system only runs if each condition returns the associated `bool`.
+    pub conditions: Vec<(
+        Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>,
+        bool,
+    )>,
 }
 
 impl Extend<WorkloadSystem> for Workload {
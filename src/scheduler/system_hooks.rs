@@ -0,0 +1,16 @@
+use crate::scheduler::Label;
+use core::time::Duration;
+
+/// Callbacks invoked around each system of a workload, see [`World::run_workload_with_hooks`].
+///
+/// Systems of the same batch can run in parallel, hooks can be called from multiple threads at the same time.
+///
+/// [`World::run_workload_with_hooks`]: crate::World::run_workload_with_hooks
+pub trait SystemHooks: Sync {
+    /// Called before a system runs.
+    #[allow(unused_variables)]
+    fn on_system_start(&self, system: &dyn Label) {}
+    /// Called after a system ran, even if it returned an error.
+    #[allow(unused_variables)]
+    fn on_system_end(&self, system: &dyn Label, duration: Duration) {}
+}
@@ -13,6 +13,35 @@ use core::ops::Not;
 /// Modifies a system.
 pub trait SystemModificator<B, R> {
     /// Only run the system if the function evaluates to `true`.
+    ///
+    /// The function can borrow views like a system.\
+    /// If a view can't be borrowed, running the workload returns [`RunWorkload::Run`](crate::error::RunWorkload::Run).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{SystemModificator, Unique, UniqueView, UniqueViewMut, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct GameState {
+    ///     paused: bool,
+    /// }
+    ///
+    /// #[derive(Unique)]
+    /// struct Ticks(u32);
+    ///
+    /// fn tick(mut ticks: UniqueViewMut<Ticks>) {
+    ///     ticks.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(GameState { paused: true });
+    /// world.add_unique(Ticks(0));
+    ///
+    /// world.add_workload(|| tick.run_if(|state: UniqueView<GameState>| !state.paused));
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Ticks>>().unwrap().0, 0);
+    /// ```
     fn run_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem;
     /// Only run the system if the `T` storage is empty.
     ///
@@ -95,8 +124,12 @@ pub trait SystemModificator<B, R> {
         self.skip_if(should_skip)
     }
     /// When building a workload, this system will be placed before all invocation of the other system or workload.
+    ///
+    /// Requirements are transitive, cyclic requirements make the build fail with [`InvalidSystem::CyclicDependency`](crate::error::InvalidSystem::CyclicDependency).
     fn before_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
     /// When building a workload, this system will be placed after all invocation of the other system or workload.
+    ///
+    /// Requirements are transitive, cyclic requirements make the build fail with [`InvalidSystem::CyclicDependency`](crate::error::InvalidSystem::CyclicDependency).
     fn after_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
     /// System name used in error and gui built for shipyard.  
     /// Defaults to the system function name.
@@ -115,6 +148,11 @@ pub trait SystemModificator<B, R> {
     ///
     /// Does not change system ordering.
     fn require_after<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
+    /// Systems with a higher priority are dispatched first when sharing a parallel batch with other systems.\
+    /// Defaults to 0.
+    ///
+    /// This is only a scheduling hint, it doesn't change system ordering or which systems run in parallel.
+    fn priority(self, priority: i32) -> WorkloadSystem;
 }
 
 pub struct Nothing;
@@ -194,6 +232,14 @@ where
 
         system.require_after.add(other);
 
+        system
+    }
+    #[track_caller]
+    fn priority(self, priority: i32) -> WorkloadSystem {
+        let mut system = self.into_workload_system().unwrap();
+
+        system.priority = priority;
+
         system
     }
 }
@@ -257,6 +303,11 @@ impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
     fn require_after<T>(mut self, other: impl AsLabel<T>) -> WorkloadSystem {
         self.require_after.add(other);
 
+        self
+    }
+    fn priority(mut self, priority: i32) -> WorkloadSystem {
+        self.priority = priority;
+
         self
     }
 }
@@ -344,6 +395,14 @@ macro_rules! impl_into_workload_system {
 
                 system.require_after.add(other);
 
+                system
+            }
+            #[track_caller]
+            fn priority(self, priority: i32) -> WorkloadSystem {
+                let mut system = IntoWorkloadSystem::<($($type,)+), Ret>::into_workload_system(self).unwrap();
+
+                system.priority = priority;
+
                 system
             }
         }
@@ -1,14 +1,15 @@
-use crate::all_storages::AllStorages;
+use crate::all_storages::{AllStorages, SeededHasher};
 use crate::borrow::Mutability;
 use crate::component::{Component, Unique};
 use crate::scheduler::info::{
     BatchInfo, Conflict, DedupedLabels, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
 };
-use crate::scheduler::label::{SystemLabel, WorkloadLabel};
+use crate::scheduler::into_workload_run_if::IntoWorkloadRunIf;
+use crate::scheduler::label::{BranchLabel, SystemLabel, WorkloadLabel};
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
 use crate::scheduler::{
-    AsLabel, Batches, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label, Scheduler,
-    WorkloadSystem,
+    AsLabel, Batches, ErrorHandler, ErrorResolution, IntoWorkload, IntoWorkloadSystem,
+    IntoWorkloadTrySystem, Label, RepeatedBatches, Scheduler, WorkloadModificator, WorkloadSystem,
 };
 use crate::storage::StorageId;
 use crate::type_id::TypeId;
@@ -17,6 +18,7 @@ use crate::world::World;
 use crate::{error, ShipHashMap};
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::sync::Arc;
 // macro not module
 use alloc::vec;
 use alloc::vec::Vec;
@@ -66,6 +68,8 @@ impl ScheduledWorkload {
             &self.system_names,
             &self.workloads[&self.name],
             &self.name,
+            0,
+            None,
         )
     }
 
@@ -137,6 +141,23 @@ pub struct Workload {
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) repeated: Vec<RepeatedWorkload>,
+    /// `run_if` is evaluated a single time for all systems when merged in another workload
+    pub(super) grouped: bool,
+    pub(super) max_threads: Option<usize>,
+    pub(super) on_error: Option<ErrorHandler>,
+}
+
+/// Workload running multiple times in a row inside another workload
+pub(super) struct RepeatedWorkload {
+    /// The workload runs after all systems before this index and before all systems after it
+    pub(super) position: usize,
+    pub(super) workload: Workload,
+    pub(super) count: Box<dyn Fn(&World) -> usize + Send + Sync>,
+    pub(super) conditions: Vec<(
+        Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>,
+        bool,
+    )>,
 }
 
 impl Workload {
@@ -195,6 +216,10 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            repeated: Vec::new(),
+            grouped: false,
+            max_threads: None,
+            on_error: None,
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.  
@@ -217,12 +242,97 @@ impl Workload {
                 .drain(..)
                 .map(|barrier| barrier + systems_len),
         );
+        self.repeated
+            .extend(other.repeated.drain(..).map(|mut repeated| {
+                repeated.position += systems_len;
+                repeated
+            }));
 
         self.append(&mut other)
     }
+    /// Same as [`Workload::merge`] but `other`'s systems already present in `self` are skipped.\
+    /// Useful to compose a workload from fragments sharing some systems.
+    ///
+    /// ### Errors
+    ///
+    /// - Two different systems share the same name.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn physics() {}
+    /// fn render() {}
+    /// fn audio() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// let core = Workload::new("core").with_system(physics).with_system(render);
+    /// let plugin = Workload::new("plugin").with_system(physics).with_system(audio);
+    ///
+    /// core.try_merge(plugin).unwrap().add_to_world(&world).unwrap();
+    ///
+    /// assert_eq!(world.workload_system_labels("core").unwrap().len(), 3);
+    /// ```
+    pub fn try_merge(mut self, mut other: Workload) -> Result<Workload, error::InvalidSystem> {
+        self.propagate();
+        other.propagate();
+
+        let systems_len = self.systems.len();
+        // number of systems kept before each index of `other.systems`
+        let mut kept_before = Vec::with_capacity(other.systems.len() + 1);
+        let mut kept = Vec::with_capacity(other.systems.len());
+
+        for system in other.systems.drain(..) {
+            kept_before.push(kept.len());
+
+            match self
+                .systems
+                .iter()
+                .find(|self_system| self_system.display_name == system.display_name)
+            {
+                Some(self_system) if self_system.type_id == system.type_id => {}
+                Some(_) => {
+                    return Err(error::InvalidSystem::ConflictingName(
+                        format!("{:?}", system.display_name).into(),
+                    ));
+                }
+                None => kept.push(system),
+            }
+        }
+        kept_before.push(kept.len());
+
+        self.barriers.extend(
+            other
+                .barriers
+                .drain(..)
+                .map(|barrier| kept_before[barrier] + systems_len),
+        );
+        self.repeated
+            .extend(other.repeated.drain(..).map(|mut repeated| {
+                repeated.position = kept_before[repeated.position] + systems_len;
+                repeated
+            }));
+        self.systems.extend(kept);
+
+        Ok(self)
+    }
     /// Propagates all information into the systems.  
     /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
     fn propagate(&mut self) {
+        let mut condition = None;
+
+        if self.grouped {
+            self.grouped = false;
+
+            if let Some(run_if) = self.run_if.take() {
+                let group_condition: Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync> =
+                    Arc::new(move |world: &World| run_if.run(world));
+
+                condition = Some(group_condition);
+            }
+        }
+
         for system in &mut self.systems {
             system.run_if = match (system.run_if.take(), self.run_if.clone()) {
                 (None, None) => None,
@@ -245,6 +355,16 @@ impl Workload {
                 .extend(self.require_after.iter().cloned());
         }
 
+        // repeated workloads are scheduled on their own, only `run_if` has to reach them
+        for repeated in &mut self.repeated {
+            repeated.workload.propagate();
+            repeated.workload.run_if = self.run_if.clone();
+        }
+
+        if let Some(condition) = condition {
+            self.add_condition(condition, true);
+        }
+
         self.run_if = None;
         self.tags.clear();
         self.before_all.clear();
@@ -477,6 +597,71 @@ impl Workload {
 
         Ok(())
     }
+    /// Same as [`Workload::add_to_world`] but replaces the workload with the same name if there is one.\
+    /// If the new workload can't be added, the previous one is kept.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared)
+    /// - Systems' storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Nested workload is not present in `world`.
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("update")
+    ///     .with_system(sys1)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     Workload::new("update").with_system(sys2).add_to_world(&world),
+    ///     Err(error::AddWorkload::AlreadyExists)
+    /// );
+    ///
+    /// Workload::new("update")
+    ///     .with_system(sys2)
+    ///     .replace_in_world(&world)
+    ///     .unwrap();
+    /// ```
+    pub fn replace_in_world(self, world: &World) -> Result<(), error::AddWorkload> {
+        let previous = world
+            .scheduler
+            .borrow_mut()
+            .map_err(|_| error::AddWorkload::Borrow)?
+            .remove_workload(&*self.name);
+
+        let result = self.add_to_world(world);
+
+        if result.is_err() {
+            if let Some((label, batches, info)) = previous {
+                let mut scheduler = world
+                    .scheduler
+                    .borrow_mut()
+                    .map_err(|_| error::AddWorkload::Borrow)?;
+
+                if let Some(info) = info {
+                    scheduler.workloads_info.insert(label.clone(), info);
+                }
+                scheduler.workloads.insert(label, batches);
+            }
+        }
+
+        result
+    }
     /// Returns the first [`Unique`] storage borrowed by this workload that is not present in `world`.\
     /// If the workload contains nested workloads they have to be present in the `World`.
     ///
@@ -511,6 +696,10 @@ impl Workload {
             }
         }
 
+        for repeated in &self.repeated {
+            repeated.workload.are_all_uniques_present_in_world(world)?;
+        }
+
         Ok(())
     }
     /// Build the [`Workload`](super::Workload) from the [`Workload`].
@@ -546,12 +735,271 @@ impl Workload {
 
         self
     }
+    /// Runs at most `max_threads` systems of this workload at the same time.\
+    /// `1` runs the workload sequentially, `0` is treated as `1`.
+    ///
+    /// Only applies to the workload it is set on, not to workloads it is merged in.\
+    /// Without the `parallel` feature, systems always run sequentially.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    /// fn sys3() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("capped")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .with_system(sys3)
+    ///     .with_max_threads(2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// ```
+    pub fn with_max_threads(mut self, max_threads: usize) -> Self {
+        self.max_threads = Some(max_threads.max(1));
+
+        self
+    }
+    /// Calls `f` when a system of this workload returns an error.\
+    /// [`ErrorResolution::Skip`] ignores the error and keeps running the workload, [`ErrorResolution::Abort`] returns the error like without a handler.
+    ///
+    /// Only applies to the workload it is set on, not to workloads it is merged in.\
+    /// Systems of the same batch can run in parallel, `f` can be called from multiple threads at the same time.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ErrorResolution, Workload, World};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// fn optional() -> Result<(), &'static str> {
+    ///     Err("flaky")
+    /// }
+    ///
+    /// fn required() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// let failures = Arc::new(AtomicUsize::new(0));
+    /// let counter = failures.clone();
+    /// Workload::new("tick")
+    ///     .with_try_system(optional)
+    ///     .with_system(required)
+    ///     .on_error(move |_, _| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///
+    ///         ErrorResolution::Skip
+    ///     })
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert_eq!(failures.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn on_error<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&dyn Label, &error::Run) -> ErrorResolution + Send + Sync + 'static,
+    {
+        self.on_error = Some(Box::new(f));
+
+        self
+    }
+    /// Adds `other` to this workload, running it `count` times in a row.
+    ///
+    /// `count` is evaluated a single time per run, when the repeated segment is reached.\
+    /// The segment acts as a barrier: it runs after all systems added before it and before all systems added after it.\
+    /// `other` is batched on its own, its systems can't be ordered relative to this workload's systems.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Accumulator(f32);
+    ///
+    /// #[derive(Unique)]
+    /// struct Steps(u32);
+    ///
+    /// const TIMESTEP: f32 = 0.25;
+    ///
+    /// fn physics(mut accumulator: UniqueViewMut<Accumulator>, mut steps: UniqueViewMut<Steps>) {
+    ///     accumulator.0 -= TIMESTEP;
+    ///     steps.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Accumulator(1.0));
+    /// world.add_unique(Steps(0));
+    ///
+    /// Workload::new("main")
+    ///     .with_repeated_workload(Workload::new("physics").with_system(physics), |world| {
+    ///         (world.borrow::<UniqueView<Accumulator>>().unwrap().0 / TIMESTEP) as usize
+    ///     })
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Steps>>().unwrap().0, 4);
+    /// ```
+    pub fn with_repeated_workload<F: Fn(&World) -> usize + Send + Sync + 'static>(
+        mut self,
+        other: Workload,
+        count: F,
+    ) -> Workload {
+        let position = self.systems.len();
+
+        self.barriers.push(position);
+        self.repeated.push(RepeatedWorkload {
+            position,
+            workload: other,
+            count: Box::new(count),
+            conditions: Vec::new(),
+        });
+
+        self
+    }
+    /// Creates a workload running either `if_true` or `if_false` depending on `cond`.
+    ///
+    /// `cond` is evaluated a single time per run, when the first system of either branch is reached.\
+    /// The branches are batched independently, all `if_true` batches come before `if_false` ones.
+    /// Both branches' borrows are validated when the workload is built.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Paused(bool);
+    ///
+    /// #[derive(Unique, Default)]
+    /// struct Frames(u32);
+    ///
+    /// fn pause_menu(mut paused: UniqueViewMut<Paused>) {
+    ///     paused.0 = false;
+    /// }
+    ///
+    /// fn game(mut frames: UniqueViewMut<Frames>) {
+    ///     frames.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Paused(true));
+    /// world.add_unique(Frames(0));
+    ///
+    /// Workload::branch(
+    ///     |paused: UniqueView<Paused>| paused.0,
+    ///     Workload::new("pause").with_system(pause_menu),
+    ///     Workload::new("game").with_system(game),
+    /// )
+    /// .rename("main")
+    /// .add_to_world(&world)
+    /// .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Frames>>().unwrap().0, 0);
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Frames>>().unwrap().0, 1);
+    /// ```
+    #[track_caller]
+    pub fn branch<RunB, Run: IntoWorkloadRunIf<RunB>>(
+        cond: Run,
+        if_true: Workload,
+        if_false: Workload,
+    ) -> Workload {
+        let cond = cond.into_workload_run_if().unwrap();
+        let condition: Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync> =
+            Arc::new(move |world: &World| cond.run(world));
+        let true_label = BranchLabel::new();
+
+        let mut if_true = if_true.tag(true_label);
+        if_true.propagate();
+        if_true.add_condition(condition.clone(), true);
+
+        let mut if_false = if_false.after_all(true_label);
+        if_false.propagate();
+        if_false.add_condition(condition, false);
+
+        Workload::new(BranchLabel::new())
+            .merge(if_true)
+            .merge(if_false)
+    }
+    /// Makes all systems and repeated workloads run only when `condition` returns `expected`.\
+    /// The workload has to be propagated first.
+    fn add_condition(
+        &mut self,
+        condition: Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>,
+        expected: bool,
+    ) {
+        // Outer conditions are checked first
+        for system in &mut self.systems {
+            system.conditions.insert(0, (condition.clone(), expected));
+        }
+        for repeated in &mut self.repeated {
+            repeated.conditions.insert(0, (condition.clone(), expected));
+        }
+    }
+    /// Groups systems so the workload's `run_if`/`skip_if` are evaluated a single time for all of them.
+    ///
+    /// When merged in another workload, the systems are batched like any other system
+    /// but the condition is evaluated once, before any system of the group runs, and all systems skip together.\
+    /// When a system has its own `run_if`, both the group's and the system's conditions have to pass.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{IntoWorkload, Unique, UniqueView, UniqueViewMut, Workload, WorkloadModificator, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Enabled(bool);
+    ///
+    /// #[derive(Unique)]
+    /// struct Count(u32);
+    ///
+    /// fn disable(mut enabled: UniqueViewMut<Enabled>) {
+    ///     enabled.0 = false;
+    /// }
+    ///
+    /// fn count(mut count: UniqueViewMut<Count>) {
+    ///     count.0 += 1;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Enabled(true));
+    /// world.add_unique(Count(0));
+    ///
+    /// Workload::new("main")
+    ///     .with_workload(
+    ///         Workload::group((disable, count).into_sequential_workload())
+    ///             .run_if(|enabled: UniqueView<Enabled>| enabled.0),
+    ///     )
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+    /// ```
+    pub fn group<Views, R, W: IntoWorkload<Views, R>>(systems: W) -> Workload {
+        let mut workload = systems.into_workload();
+        workload.grouped = true;
+
+        workload
+    }
 }
 
 fn check_uniques_in_systems(
     system: &WorkloadSystem,
     unique_name: &str,
-    storages: &ShipHashMap<StorageId, crate::storage::SBox>,
+    storages: &hashbrown::HashMap<StorageId, crate::storage::SBox, SeededHasher>,
 ) -> Option<Result<(), error::UniquePresence>> {
     let WorkloadSystem {
         borrow_constraints, ..
@@ -582,6 +1030,33 @@ fn create_workload(
         return Err(error::AddWorkload::AlreadyExists);
     }
 
+    if workloads.is_empty() {
+        *default = builder.name.clone();
+    }
+
+    let batches = workloads.entry(builder.name.clone()).or_default();
+
+    create_batches(
+        builder,
+        batches,
+        systems,
+        system_names,
+        system_generators,
+        lookup_table,
+        tracking_to_enable,
+    )
+}
+
+#[allow(clippy::type_complexity)]
+fn create_batches(
+    mut builder: Workload,
+    batches: &mut Batches,
+    systems: &mut Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
+    system_names: &mut Vec<Box<dyn Label>>,
+    system_generators: &mut Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
+    lookup_table: &mut ShipHashMap<TypeId, usize>,
+    tracking_to_enable: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+) -> Result<WorkloadInfo, error::AddWorkload> {
     for index in builder.barriers.drain(..) {
         let tag = format!("__barrier__{}", index);
 
@@ -594,6 +1069,41 @@ fn create_workload(
         }
     }
 
+    let mut repeated = Vec::with_capacity(builder.repeated.len());
+    for RepeatedWorkload {
+        position,
+        workload,
+        count,
+        conditions,
+    } in builder.repeated.drain(..)
+    {
+        let name = workload.name.clone();
+        let mut repeated_batches = Batches::default();
+
+        let info = create_batches(
+            workload,
+            &mut repeated_batches,
+            systems,
+            system_names,
+            system_generators,
+            lookup_table,
+            tracking_to_enable,
+        )?;
+
+        repeated.push((
+            position,
+            RepeatedBatches {
+                parallel_position: 0,
+                sequential_position: 0,
+                name,
+                batches: repeated_batches,
+                count,
+                conditions: index_conditions(batches, conditions),
+            },
+            info,
+        ));
+    }
+
     let mut collected_systems: Vec<(usize, WorkloadSystem)> =
         Vec::with_capacity(builder.systems.len());
 
@@ -612,13 +1122,9 @@ fn create_workload(
         );
     }
 
-    if workloads.is_empty() {
-        *default = builder.name.clone();
-    }
-
-    let batches = workloads.entry(builder.name.clone()).or_default();
-
     batches.run_if = builder.run_if;
+    batches.max_threads = builder.max_threads;
+    batches.on_error = builder.on_error;
 
     if collected_systems.len() == 1 {
         let (
@@ -628,6 +1134,7 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                conditions,
                 ..
             },
         ) = collected_systems.pop().unwrap();
@@ -653,8 +1160,11 @@ fn create_workload(
             batches.parallel_run_if.push((None, vec![0]));
         }
 
+        let conditions = index_conditions(batches, conditions);
+
         batches.sequential.push(system_index);
         batches.sequential_run_if.push(run_if);
+        batches.sequential_conditions.push(conditions);
 
         let batch_info = BatchInfo {
             systems: (
@@ -670,10 +1180,20 @@ fn create_workload(
             ),
         };
 
-        return Ok(WorkloadInfo {
+        let mut workload_info = WorkloadInfo {
             name: format!("{:?}", builder.name),
             batch_info: vec![batch_info],
-        });
+        };
+
+        insert_repeated(
+            batches,
+            &mut workload_info,
+            repeated,
+            &[0],
+            &[(None, vec![0])],
+        );
+
+        return Ok(workload_info);
     }
 
     let mut workload_info = WorkloadInfo {
@@ -717,11 +1237,13 @@ fn create_workload(
     let mut collected_before = Vec::new();
     let mut collected_after = Vec::new();
     let mut collected_names = Vec::new();
+    let mut priorities: ShipHashMap<usize, i32> =
+        ShipHashMap::with_hasher(BuildHasherDefault::default());
 
     for (
         index,
         (
-            _,
+            system_index,
             WorkloadSystem {
                 before_all,
                 after_all,
@@ -730,11 +1252,15 @@ fn create_workload(
                 require_before,
                 require_after,
                 display_name,
+                priority,
                 ..
             },
         ),
     ) in collected_systems.iter_mut().enumerate()
     {
+        let system_priority = priorities.entry(*system_index).or_insert(*priority);
+        *system_priority = (*system_priority).max(*priority);
+
         memoize_before.insert(index, before_all.clone());
         memoize_after.insert(index, after_all.clone());
         collected_tags.push(core::mem::take(tags));
@@ -765,6 +1291,10 @@ fn create_workload(
         });
     }
 
+    // Kept to report the systems forming a cycle
+    let direct_before = memoize_before.clone();
+    let direct_after = memoize_after.clone();
+
     let mut new_requirements = true;
     while new_requirements {
         new_requirements = false;
@@ -837,12 +1367,24 @@ fn create_workload(
                 .iter()
                 .any(|after_requirement| after_requirement == before_requirement)
             {
-                return Err(error::AddWorkload::ImpossibleRequirements(
-                    error::ImpossibleRequirements::BeforeAndAfter(
-                        collected_systems[*before].1.display_name.clone(),
-                        before_requirement.clone(),
+                let err = match cycle(*before, &collected_tags, &direct_before, &direct_after) {
+                    Some(cycle) => {
+                        error::AddWorkload::InvalidSystem(error::InvalidSystem::CyclicDependency(
+                            cycle
+                                .into_iter()
+                                .map(|index| format!("{:?}", collected_names[index]).into())
+                                .collect(),
+                        ))
+                    }
+                    None => error::AddWorkload::ImpossibleRequirements(
+                        error::ImpossibleRequirements::BeforeAndAfter(
+                            collected_systems[*before].1.display_name.clone(),
+                            before_requirement.clone(),
+                        ),
                     ),
-                ));
+                };
+
+                return Err(err);
             }
         }
     }
@@ -866,12 +1408,15 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                conditions,
                 tags: _,
                 ..
             },
         ),
     ) in collected_systems
     {
+        let conditions = index_conditions(batches, conditions);
+
         insert_system(
             batches,
             &mut workload_info,
@@ -881,6 +1426,7 @@ fn create_workload(
             display_name,
             borrow_constraints,
             run_if,
+            conditions,
             &mut seq_system_index_map,
             &mut par_system_index_map,
         );
@@ -895,12 +1441,15 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                conditions,
                 tags: _,
                 ..
             },
         ),
     ) in before_after_collected_systems
     {
+        let conditions = index_conditions(batches, conditions);
+
         insert_before_after_system(
             batches,
             &mut workload_info,
@@ -911,6 +1460,7 @@ fn create_workload(
             &display_name,
             borrow_constraints,
             run_if,
+            conditions,
             &memoize_before,
             &memoize_after,
             &mut seq_system_index_map,
@@ -918,6 +1468,25 @@ fn create_workload(
         )?;
     }
 
+    // Dispatch systems with a higher priority first, the sort is stable to keep the workload order otherwise
+    for (batch, batch_run_if) in batches
+        .parallel
+        .iter_mut()
+        .zip(&mut batches.parallel_run_if)
+    {
+        let mut parallel_systems: Vec<(usize, usize)> = batch
+            .1
+            .iter()
+            .copied()
+            .zip(batch_run_if.1.iter().copied())
+            .collect();
+
+        parallel_systems
+            .sort_by_key(|(system_index, _)| core::cmp::Reverse(priorities[system_index]));
+
+        (batch.1, batch_run_if.1) = parallel_systems.into_iter().unzip();
+    }
+
     for (i, &index) in seq_system_index_map.iter().enumerate() {
         let mut require_in_workload = collected_require_in_workload[index].to_vec();
         let mut require_before = collected_before[index].to_vec();
@@ -1014,9 +1583,86 @@ fn create_workload(
         }
     }
 
+    insert_repeated(
+        batches,
+        &mut workload_info,
+        repeated,
+        &seq_system_index_map,
+        &par_system_index_map,
+    );
+
     Ok(workload_info)
 }
 
+/// Stores shared conditions in `batches` once and returns their index.
+#[allow(clippy::type_complexity)]
+fn index_conditions(
+    batches: &mut Batches,
+    conditions: Vec<(
+        Arc<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>,
+        bool,
+    )>,
+) -> Vec<(usize, bool)> {
+    conditions
+        .into_iter()
+        .map(|(condition, expected)| {
+            let index = batches
+                .conditions
+                .iter()
+                .position(|other| {
+                    Arc::as_ptr(other) as *const () == Arc::as_ptr(&condition) as *const ()
+                })
+                .unwrap_or_else(|| {
+                    batches.conditions.push(condition);
+
+                    batches.conditions.len() - 1
+                });
+
+            (index, expected)
+        })
+        .collect()
+}
+
+/// Places repeated workloads after all systems added before them.
+fn insert_repeated(
+    batches: &mut Batches,
+    workload_info: &mut WorkloadInfo,
+    repeated: Vec<(usize, RepeatedBatches, WorkloadInfo)>,
+    seq_system_index_map: &[usize],
+    par_system_index_map: &[(Option<usize>, Vec<usize>)],
+) {
+    let mut repeated_info = Vec::with_capacity(repeated.len());
+
+    for (position, mut repeated_batches, info) in repeated {
+        let sequential_position = seq_system_index_map
+            .iter()
+            .filter(|&&index| index < position)
+            .count();
+        let parallel_position = par_system_index_map
+            .iter()
+            .rposition(|(single_system, systems)| {
+                single_system
+                    .iter()
+                    .chain(systems)
+                    .any(|&index| index < position)
+            })
+            .map_or(0, |batch| batch + 1);
+
+        repeated_batches.parallel_position = parallel_position;
+        repeated_batches.sequential_position = sequential_position;
+
+        repeated_info.push((parallel_position, info));
+        batches.repeated.push(repeated_batches);
+    }
+
+    // Inserted last to first to keep the position of the batches that come before
+    for (parallel_position, info) in repeated_info.into_iter().rev() {
+        workload_info
+            .batch_info
+            .splice(parallel_position..parallel_position, info.batch_info);
+    }
+}
+
 #[allow(clippy::needless_range_loop)]
 fn dependencies(
     index: usize,
@@ -1045,6 +1691,62 @@ fn dependencies(
     Ok(())
 }
 
+/// Returns the indices of the systems forming a cycle through `start`, starting and ending with `start`.\
+/// Only the requirements set directly on the systems are followed.
+fn cycle(
+    start: usize,
+    collected_tags: &[Vec<Box<dyn Label>>],
+    direct_before: &ShipHashMap<usize, DedupedLabels>,
+    direct_after: &ShipHashMap<usize, DedupedLabels>,
+) -> Option<Vec<usize>> {
+    // index has to run before other_index
+    let is_before = |index: usize, other_index: usize| {
+        index != other_index
+            && (direct_before[&index]
+                .iter()
+                .any(|label| collected_tags[other_index].contains(label))
+                || direct_after[&other_index]
+                    .iter()
+                    .any(|label| collected_tags[index].contains(label)))
+    };
+
+    let mut previous = vec![None; collected_tags.len()];
+    let mut queue = vec![start];
+    let mut i = 0;
+
+    while let Some(&index) = queue.get(i) {
+        i += 1;
+
+        for other_index in 0..collected_tags.len() {
+            if !is_before(index, other_index) {
+                continue;
+            }
+
+            if other_index == start {
+                let mut path = vec![start];
+                let mut current = index;
+
+                while current != start {
+                    path.push(current);
+                    current = previous[current]?;
+                }
+
+                path[1..].reverse();
+                path.push(start);
+
+                return Some(path);
+            }
+
+            if previous[other_index].is_none() {
+                previous[other_index] = Some(index);
+                queue.push(other_index);
+            }
+        }
+    }
+
+    None
+}
+
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn insert_system(
     batches: &mut Batches,
@@ -1055,11 +1757,13 @@ fn insert_system(
     display_name: Box<dyn Label>,
     borrow_constraints: Vec<TypeInfo>,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    conditions: Vec<(usize, bool)>,
     seq_system_index_map: &mut Vec<usize>,
     par_system_index_map: &mut Vec<(Option<usize>, Vec<usize>)>,
 ) {
     batches.sequential.push(system_index);
     batches.sequential_run_if.push(run_if);
+    batches.sequential_conditions.push(conditions);
     seq_system_index_map.push(index);
 
     let mut valid = batches.parallel.len();
@@ -1340,6 +2044,7 @@ fn insert_before_after_system(
     display_name: &dyn Label,
     borrow_constraints: Vec<TypeInfo>,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    conditions: Vec<(usize, bool)>,
     memoize_before: &ShipHashMap<usize, DedupedLabels>,
     memoize_after: &ShipHashMap<usize, DedupedLabels>,
     seq_system_index_map: &mut Vec<usize>,
@@ -1371,6 +2076,9 @@ fn insert_before_after_system(
     batches
         .sequential_run_if
         .insert(sequential_position, run_if);
+    batches
+        .sequential_conditions
+        .insert(sequential_position, conditions);
     seq_system_index_map.insert(sequential_position, index);
 
     for (single_run_if, run_if_indices) in &mut batches.parallel_run_if {
@@ -1694,7 +2402,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1724,7 +2437,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1756,12 +2474,53 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
     }
 
+    #[test]
+    fn priority() {
+        use crate::{SystemModificator, View, World};
+
+        fn system1(_: View<'_, Usize>) {}
+        fn system2(_: View<'_, Usize>) {}
+        fn system3(_: View<'_, Usize>) {}
+
+        let world = World::new();
+
+        Workload::new("Systems")
+            .with_system(system1)
+            .with_system(system2.priority(-1))
+            .with_system(system3.priority(10))
+            .add_to_world(&world)
+            .unwrap();
+
+        let scheduler = world.scheduler.borrow_mut().unwrap();
+        let label: Box<dyn Label> = Box::new("Systems");
+        assert_eq!(
+            scheduler.workloads.get(&label),
+            Some(&Batches {
+                parallel: vec![(None, vec![2, 0, 1])],
+                parallel_run_if: Vec::new(),
+                sequential: vec![0, 1, 2],
+                sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
+                run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
+            })
+        );
+    }
+
     #[test]
     fn multiple_mutable() {
         use crate::{ViewMut, World};
@@ -1788,7 +2547,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1820,7 +2584,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1844,7 +2613,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1883,7 +2657,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1914,7 +2693,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1937,7 +2721,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1961,7 +2750,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1984,7 +2778,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2026,7 +2825,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2052,7 +2856,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2075,7 +2884,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2098,7 +2912,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2124,7 +2943,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2156,7 +2980,51 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
+            })
+        );
+        assert_eq!(&scheduler.default, &label);
+    }
+
+    #[test]
+    fn different_uniques_mutable() {
+        use crate::{UniqueViewMut, View, World};
+
+        fn system1(_: View<'_, Usize>, _: UniqueViewMut<'_, Usize>) {}
+        fn system2(_: View<'_, Usize>, _: UniqueViewMut<'_, U32>) {}
+        fn system3(_: View<'_, U32>, _: UniqueViewMut<'_, Usize>) {}
+
+        let world = World::new();
+
+        Workload::new("Systems")
+            .with_system(system1)
+            .with_system(system2)
+            .with_system(system3)
+            .add_to_world(&world)
+            .unwrap();
+
+        let scheduler = world.scheduler.borrow_mut().unwrap();
+        let label: Box<dyn Label> = Box::new("Systems");
+        assert_eq!(scheduler.systems.len(), 3);
+        assert_eq!(scheduler.workloads.len(), 1);
+        assert_eq!(
+            scheduler.workloads.get(&label),
+            Some(&Batches {
+                parallel: vec![(None, vec![0, 1]), (None, vec![2])],
+                parallel_run_if: Vec::new(),
+                sequential: vec![0, 1, 2],
+                sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
+                run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2181,7 +3049,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2222,7 +3095,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2401,6 +3279,48 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn before_after_cycle() {
+        fn type_name_of<T: 'static>(_: T) -> &'static str {
+            type_name::<T>()
+        }
+
+        fn a() {}
+        fn b() {}
+        fn c() {}
+
+        let result = Workload::new("")
+            .with_system(a.after_all(c))
+            .with_system(b.after_all(a))
+            .with_system(c.after_all(b))
+            .build();
+
+        let Err(error::AddWorkload::InvalidSystem(error::InvalidSystem::CyclicDependency(cycle))) =
+            result
+        else {
+            panic!("expected a cyclic dependency")
+        };
+
+        let names = [type_name_of(a), type_name_of(b), type_name_of(c)];
+        let start = names.iter().position(|name| *name == cycle[0]).unwrap();
+        let expected = (0..=3).map(|i| names[(start + i) % 3]).collect::<Vec<_>>();
+
+        assert_eq!(cycle, expected);
+
+        let result = Workload::new("")
+            .with_system(a.before_all(b))
+            .with_system(b.before_all(c))
+            .with_system(c.before_all(a))
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(error::AddWorkload::InvalidSystem(
+                error::InvalidSystem::CyclicDependency(cycle)
+            )) if cycle.len() == 4 && cycle[0] == cycle[3]
+        ));
+    }
+
     #[test]
     fn before_after_no_anchor() {
         fn a() {}
@@ -2518,7 +3438,12 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
                 run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
             }
         );
     }
@@ -2645,4 +3570,75 @@ mod tests {
             .custom_error()
             .is_some());
     }
+
+    #[test]
+    fn custom_borrow_info() {
+        use crate::atomic_refcell::SharedBorrow;
+        use crate::borrow::{Borrow, BorrowInfo, Mutability};
+        use crate::sparse_set::SparseSet;
+        use crate::storage::StorageId;
+        use crate::tracking::TrackingTimestamp;
+        use crate::{AllStorages, ViewMut, World};
+
+        struct UsizeQuery<'v>(#[allow(unused)] View<'v, Usize>);
+
+        impl Borrow for UsizeQuery<'_> {
+            type View<'v> = UsizeQuery<'v>;
+
+            fn borrow<'a>(
+                all_storages: &'a AllStorages,
+                all_borrow: Option<SharedBorrow<'a>>,
+                last_run: Option<TrackingTimestamp>,
+                current: TrackingTimestamp,
+            ) -> Result<Self::View<'a>, error::GetStorage> {
+                Ok(UsizeQuery(View::<Usize>::borrow(
+                    all_storages,
+                    all_borrow,
+                    last_run,
+                    current,
+                )?))
+            }
+        }
+
+        unsafe impl BorrowInfo for UsizeQuery<'_> {
+            fn borrow_info(info: &mut Vec<TypeInfo>) {
+                Self::extend_custom_borrow_info(info);
+            }
+            fn custom_borrow_info() -> Vec<(StorageId, Mutability)> {
+                vec![(StorageId::of::<SparseSet<Usize>>(), Mutability::Shared)]
+            }
+            fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+        }
+
+        fn system1(_: UsizeQuery<'_>) {}
+        fn system2(_: ViewMut<'_, U32>) {}
+        fn system3(_: ViewMut<'_, Usize>) {}
+
+        let world = World::new();
+
+        Workload::new("Systems")
+            .with_system(system1)
+            .with_system(system2)
+            .with_system(system3)
+            .add_to_world(&world)
+            .unwrap();
+
+        let scheduler = world.scheduler.borrow_mut().unwrap();
+        let label: Box<dyn Label> = Box::new("Systems");
+        assert_eq!(
+            scheduler.workloads.get(&label),
+            Some(&Batches {
+                parallel: vec![(None, vec![0, 1]), (None, vec![2])],
+                parallel_run_if: Vec::new(),
+                sequential: vec![0, 1, 2],
+                sequential_run_if: Vec::new(),
+                sequential_conditions: Vec::new(),
+                conditions: Vec::new(),
+                run_if: None,
+                max_threads: None,
+                on_error: None,
+                repeated: Vec::new(),
+            })
+        );
+    }
 }
@@ -6,13 +6,16 @@ use crate::scheduler::workload::Workload;
 use crate::scheduler::AsLabel;
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
+use crate::tracking::{TrackingTimestamp, TupleChanged};
 use crate::type_id::TypeId;
 use crate::unique::UniqueStorage;
-use crate::views::AllStoragesViewMut;
+use crate::views::{AllStoragesView, AllStoragesViewMut};
 use crate::world::World;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use core::any::type_name;
 use core::ops::Not;
+use core::sync::atomic::{AtomicU64, Ordering};
 
 /// Modifies a workload.
 pub trait WorkloadModificator {
@@ -57,6 +60,53 @@ pub trait WorkloadModificator {
 
         self.run_if(run_if)
     }
+    /// Only run the workload if a `T` component was inserted or modified since the last time this condition was evaluated.\
+    /// `T` can be a single component or a tuple of components.
+    ///
+    /// Storages not tracking insertion nor modification are never considered changed.\
+    /// If a storage is already borrowed, assume it changed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoWorkload, WorkloadModificator, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion, Modification)]
+    /// struct Position(f32);
+    ///
+    /// fn validate() {}
+    ///
+    /// let mut world = World::new();
+    ///
+    /// (validate,)
+    ///     .into_workload()
+    ///     .run_if_changed::<Position>()
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// // validate doesn't run, nothing changed
+    /// world.run_default_workload().unwrap();
+    ///
+    /// world.add_entity(Position(0.0));
+    ///
+    /// // validate runs
+    /// world.run_default_workload().unwrap();
+    /// ```
+    fn run_if_changed<T: 'static + TupleChanged>(self) -> Workload
+    where
+        Self: Sized,
+    {
+        let last_run = Arc::new(AtomicU64::new(0));
+
+        let run_if = move |all_storages: AllStoragesView<'_>| {
+            let current = all_storages.get_current();
+            let last = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+
+            T::is_inserted_or_modified(&all_storages, last, current)
+        };
+
+        self.run_if(run_if)
+    }
     /// Do not run the workload if the function evaluates to `true`.
     fn skip_if<RunB, Run: IntoWorkloadRunIf<RunB>>(self, run_if: Run) -> Workload;
     /// Do not run the workload if the `T` storage is empty.
@@ -49,7 +49,7 @@ impl BulkInsert for () {
 
         entities.bulk_generate(len);
         for _ in iter.skip(len) {
-            entities.generate();
+            entities.generate_new();
         }
         BulkEntityIter {
             iter: entities.data[entities_len..].iter().copied(),
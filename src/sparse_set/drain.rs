@@ -24,8 +24,14 @@ impl<'a, T> Iterator for SparseSetDrain<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.data.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
 }
 
+impl<T> ExactSizeIterator for SparseSetDrain<'_, T> {}
+
 impl<'a, T> Iterator for WithId<SparseSetDrain<'a, T>> {
     type Item = (EntityId, T);
 
@@ -42,4 +48,8 @@ impl<'a, T> Iterator for WithId<SparseSetDrain<'a, T>> {
 
         Some((id, element))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.data.size_hint()
+    }
 }
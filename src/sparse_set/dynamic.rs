@@ -0,0 +1,384 @@
+use crate::entity_id::EntityId;
+use crate::sparse_set::{SparseArray, BUCKET_SIZE};
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::mem::{size_of, MaybeUninit};
+
+/// Storage unit of [`DynamicSparseSet`], components are aligned to at most 16 bytes.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct Chunk(MaybeUninit<[u8; 16]>);
+
+/// Maximum alignment of a component stored in a [`DynamicSparseSet`].
+const MAX_ALIGN: usize = core::mem::align_of::<Chunk>();
+
+/// Storage for components only known at runtime, described by a name, a [`Layout`] and a drop function.
+///
+/// Register it with a custom [`StorageId`] using [`CustomStorageAccess`],
+/// the same `StorageId` is then used to borrow it back.
+///
+/// ### Example
+/// ```
+/// use core::alloc::Layout;
+/// use shipyard::all_storages::CustomStorageAccess;
+/// use shipyard::sparse_set::DynamicSparseSet;
+/// use shipyard::{AllStoragesViewMut, StorageId, World};
+///
+/// const HEALTH: StorageId = StorageId::Custom(0);
+///
+/// let world = World::new();
+/// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+///
+/// let entity = all_storages.add_entity(());
+///
+/// let mut healths = all_storages
+///     .custom_storage_or_insert_mut_by_id(HEALTH, || unsafe {
+///         DynamicSparseSet::new("Health", Layout::new::<u32>(), None)
+///     })
+///     .unwrap();
+///
+/// unsafe {
+///     healths.insert_as(entity, 10u32);
+///     assert_eq!(healths.get_as::<u32>(entity), Some(&10));
+/// }
+/// drop(healths);
+///
+/// all_storages.delete_entity(entity);
+///
+/// let healths = all_storages.custom_storage_by_id(HEALTH).unwrap();
+/// assert_eq!(healths.len(), Some(0));
+/// ```
+///
+/// [`StorageId`]: crate::StorageId
+/// [`CustomStorageAccess`]: crate::all_storages::CustomStorageAccess
+pub struct DynamicSparseSet {
+    sparse: SparseArray<EntityId, BUCKET_SIZE>,
+    dense: Vec<EntityId>,
+    data: Vec<Chunk>,
+    /// Number of chunks used by a component
+    stride: usize,
+    layout: Layout,
+    drop: Option<unsafe fn(*mut u8)>,
+    name: Cow<'static, str>,
+}
+
+impl DynamicSparseSet {
+    /// Creates an empty storage for components described by `layout`.\
+    /// `drop` is called on components when they are overwritten, deleted or when the storage is dropped.
+    ///
+    /// ### Safety
+    ///
+    /// - `drop` has to be safe to call on any component inserted in this storage.
+    /// - The components have to be `Send` and `Sync`, the storage can be moved and shared across threads.
+    ///
+    /// ### Panics
+    ///
+    /// - `layout`'s alignment is greater than 16.
+    #[track_caller]
+    #[allow(clippy::manual_div_ceil, reason = "Too recent version")]
+    pub unsafe fn new(
+        name: impl Into<Cow<'static, str>>,
+        layout: Layout,
+        drop: Option<unsafe fn(*mut u8)>,
+    ) -> Self {
+        assert!(
+            layout.align() <= MAX_ALIGN,
+            "DynamicSparseSet components can't be aligned to more than {} bytes.",
+            MAX_ALIGN
+        );
+
+        DynamicSparseSet {
+            sparse: SparseArray::new(),
+            dense: Vec::new(),
+            data: Vec::new(),
+            stride: ((layout.size() + size_of::<Chunk>() - 1) / size_of::<Chunk>()).max(1),
+            layout,
+            drop,
+            name: name.into(),
+        }
+    }
+    /// Returns the layout of the components.
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+    /// Returns the number of components in the storage.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+    /// Returns `true` if the storage is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    /// Returns `true` if `entity` owns a component in this storage.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.index_of(entity).is_some()
+    }
+    /// Returns the index of `entity`'s component in the dense storage.
+    #[inline]
+    pub fn index_of(&self, entity: EntityId) -> Option<usize> {
+        self.sparse.get(entity).and_then(|sparse_entity| {
+            if entity.gen() == sparse_entity.gen() {
+                Some(sparse_entity.uindex())
+            } else {
+                None
+            }
+        })
+    }
+    /// Returns the `EntityId`s of the components, in storage order.
+    #[inline]
+    pub fn ids(&self) -> &[EntityId] {
+        &self.dense
+    }
+    /// Returns a pointer to `entity`'s component.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<*const u8> {
+        self.index_of(entity)
+            .map(|index| unsafe { self.data.as_ptr().add(index * self.stride) as *const u8 })
+    }
+    /// Returns a mutable pointer to `entity`'s component.
+    #[inline]
+    pub fn get_mut(&mut self, entity: EntityId) -> Option<*mut u8> {
+        self.index_of(entity)
+            .map(|index| unsafe { self.data.as_mut_ptr().add(index * self.stride) as *mut u8 })
+    }
+    /// Returns a reference to `entity`'s component as a `T`.
+    ///
+    /// ### Safety
+    ///
+    /// The components of this storage have to be `T`s.
+    #[inline]
+    pub unsafe fn get_as<T: 'static>(&self, entity: EntityId) -> Option<&T> {
+        debug_assert_eq!(Layout::new::<T>(), self.layout);
+
+        self.get(entity).map(|ptr| &*(ptr as *const T))
+    }
+    /// Returns a mutable reference to `entity`'s component as a `T`.
+    ///
+    /// ### Safety
+    ///
+    /// The components of this storage have to be `T`s.
+    #[inline]
+    pub unsafe fn get_as_mut<T: 'static>(&mut self, entity: EntityId) -> Option<&mut T> {
+        debug_assert_eq!(Layout::new::<T>(), self.layout);
+
+        self.get_mut(entity).map(|ptr| &mut *(ptr as *mut T))
+    }
+    /// Inserts the component pointed by `component` for `entity`, the previous component is dropped.\
+    /// Returns `false` if `entity` is older than the entity owning a component at the same index,
+    /// the component is then not inserted and the caller keeps its ownership.
+    ///
+    /// ### Safety
+    ///
+    /// - `component` has to point to a valid component of this storage's layout.
+    /// - The ownership of the component is transferred to the storage when `true` is returned.
+    #[allow(clippy::manual_repeat_n, reason = "Too recent version")]
+    pub unsafe fn insert(&mut self, entity: EntityId, component: *const u8) -> bool {
+        self.sparse.allocate_at(entity);
+
+        // at this point there can't be nothing at the sparse index
+        let sparse_entity = self.sparse.get_mut_unchecked(entity);
+
+        let index = if sparse_entity.is_dead() {
+            *sparse_entity =
+                EntityId::new_from_index_and_gen(self.dense.len() as u64, entity.gen());
+
+            self.dense.push(entity);
+            self.data
+                .extend(core::iter::repeat(Chunk(MaybeUninit::uninit())).take(self.stride));
+
+            self.dense.len() - 1
+        } else if entity.gen() >= sparse_entity.gen() {
+            let index = sparse_entity.uindex();
+
+            sparse_entity.copy_gen(entity);
+            self.dense.get_unchecked_mut(index).copy_index_gen(entity);
+
+            if let Some(drop) = self.drop {
+                (drop)(self.data.as_mut_ptr().add(index * self.stride) as *mut u8);
+            }
+
+            index
+        } else {
+            return false;
+        };
+
+        core::ptr::copy_nonoverlapping(
+            component,
+            self.data.as_mut_ptr().add(index * self.stride) as *mut u8,
+            self.layout.size(),
+        );
+
+        true
+    }
+    /// Inserts `component` for `entity`, the previous component is dropped.\
+    /// Returns `false` if `entity` is older than the entity owning a component at the same index,
+    /// `component` is then dropped.
+    ///
+    /// ### Safety
+    ///
+    /// The components of this storage have to be `T`s.
+    pub unsafe fn insert_as<T: 'static>(&mut self, entity: EntityId, component: T) -> bool {
+        debug_assert_eq!(Layout::new::<T>(), self.layout);
+
+        let component = core::mem::ManuallyDrop::new(component);
+
+        if self.insert(entity, &*component as *const T as *const u8) {
+            true
+        } else {
+            drop(core::mem::ManuallyDrop::into_inner(component));
+
+            false
+        }
+    }
+    /// Deletes `entity`'s component, returns `true` if there was one.
+    pub fn delete(&mut self, entity: EntityId) -> bool {
+        let Some(sparse_entity) = self.sparse.get(entity) else {
+            return false;
+        };
+
+        if entity.gen() < sparse_entity.gen() {
+            return false;
+        }
+
+        let index = sparse_entity.uindex();
+        let last = self.dense.len() - 1;
+
+        unsafe {
+            *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+
+            if let Some(drop) = self.drop {
+                (drop)(self.data.as_mut_ptr().add(index * self.stride) as *mut u8);
+            }
+
+            if index < last {
+                core::ptr::copy_nonoverlapping(
+                    self.data.as_ptr().add(last * self.stride),
+                    self.data.as_mut_ptr().add(index * self.stride),
+                    self.stride,
+                );
+
+                let moved = *self.dense.get_unchecked(last);
+                self.sparse
+                    .get_mut_unchecked(moved)
+                    .copy_index(sparse_entity);
+            }
+        }
+
+        self.dense.swap_remove(index);
+        self.data.truncate(self.dense.len() * self.stride);
+
+        entity.gen() == sparse_entity.gen()
+    }
+    /// Deletes all components.
+    pub fn clear(&mut self) {
+        for &id in &self.dense {
+            unsafe {
+                *self.sparse.get_mut_unchecked(id) = EntityId::dead();
+            }
+        }
+
+        self.drop_all();
+
+        self.dense.clear();
+        self.data.clear();
+    }
+    fn drop_all(&mut self) {
+        if let Some(drop) = self.drop {
+            for index in 0..self.dense.len() {
+                unsafe {
+                    (drop)(self.data.as_mut_ptr().add(index * self.stride) as *mut u8);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for DynamicSparseSet {
+    fn drop(&mut self) {
+        self.drop_all();
+    }
+}
+
+impl Storage for DynamicSparseSet {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        DynamicSparseSet::delete(self, entity);
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        DynamicSparseSet::clear(self);
+    }
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+    #[inline]
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
+        Some(&self.sparse)
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        Some(self.dense.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    unsafe fn drop_string(ptr: *mut u8) {
+        core::ptr::drop_in_place(ptr as *mut String);
+    }
+
+    #[test]
+    fn insert_delete() {
+        let mut sparse_set =
+            unsafe { DynamicSparseSet::new("String", Layout::new::<String>(), Some(drop_string)) };
+
+        let e0 = EntityId::new_from_index_and_gen(0, 0);
+        let e1 = EntityId::new_from_index_and_gen(1, 0);
+        let e2 = EntityId::new_from_index_and_gen(2, 0);
+
+        unsafe {
+            assert!(sparse_set.insert_as(e0, "0".to_string()));
+            assert!(sparse_set.insert_as(e1, "1".to_string()));
+            assert!(sparse_set.insert_as(e2, "2".to_string()));
+            assert!(sparse_set.insert_as(e1, "one".to_string()));
+        }
+
+        assert_eq!(sparse_set.len(), 3);
+        assert!(sparse_set.delete(e0));
+        assert!(!sparse_set.delete(e0));
+        assert_eq!(sparse_set.ids(), &[e2, e1]);
+
+        unsafe {
+            assert_eq!(sparse_set.get_as::<String>(e0), None);
+            assert_eq!(sparse_set.get_as::<String>(e1).unwrap(), "one");
+            assert_eq!(sparse_set.get_as::<String>(e2).unwrap(), "2");
+        }
+
+        let e1_new_gen = EntityId::new_from_index_and_gen(1, 1);
+        unsafe {
+            assert!(sparse_set.insert_as(e1_new_gen, "new".to_string()));
+            assert!(!sparse_set.insert_as(e1, "old".to_string()));
+            assert_eq!(sparse_set.get_as::<String>(e1), None);
+            assert_eq!(sparse_set.get_as::<String>(e1_new_gen).unwrap(), "new");
+        }
+
+        sparse_set.clear();
+        assert!(sparse_set.is_empty());
+        assert!(!sparse_set.contains(e2));
+    }
+}
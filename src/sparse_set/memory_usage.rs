@@ -17,7 +17,7 @@ impl<T: Component> SparseSet<T> {
         }
     }
 
-    fn allocated_memory_bytes(&self) -> usize {
+    pub(super) fn allocated_memory_bytes(&self) -> usize {
         self.sparse.reserved_memory()
             + (self.dense.capacity() * size_of::<EntityId>())
             + (self.data.capacity() * size_of::<T>())
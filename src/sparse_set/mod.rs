@@ -2,18 +2,24 @@ mod add_component;
 mod bulk_add_entity;
 mod delete;
 mod drain;
+mod dynamic;
 mod memory_usage;
 mod remove;
+mod snapshot;
 mod sparse_array;
+mod spawn_columns;
 mod window;
 
 pub use add_component::TupleAddComponent;
 pub use bulk_add_entity::BulkAddEntity;
 pub use delete::TupleDelete;
 pub use drain::SparseSetDrain;
+pub use dynamic::DynamicSparseSet;
 pub use memory_usage::{SparseSetMemory, SparseSetMemoryUsage};
 pub use remove::TupleRemove;
+pub use snapshot::Snapshot;
 pub use sparse_array::SparseArray;
+pub use spawn_columns::SpawnColumns;
 #[doc(hidden)]
 pub use window::RawEntityIdAccess;
 
@@ -25,11 +31,13 @@ use crate::borrow::{NonSend, NonSendSync, NonSync};
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{MemoryUsageDetail, StorageMemoryUsage};
 use crate::r#mut::Mut;
 use crate::storage::{Storage, StorageId};
 use crate::tracking::{Tracking, TrackingTimestamp};
 use alloc::boxed::Box;
+// macro not module
+use alloc::vec;
 use alloc::vec::Vec;
 use core::any::type_name;
 use core::mem::size_of;
@@ -152,11 +160,195 @@ impl<T: Component> SparseSet<T> {
     pub unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
         self.sparse.get_unchecked(entity).uindex()
     }
+    /// Returns `entity`'s component without checking it is present nor its generation.
+    ///
+    /// # Safety
+    ///
+    /// `entity` has to own a component of this type.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, entity: EntityId) -> &T {
+        self.data.get_unchecked(self.index_of_unchecked(entity))
+    }
     /// Returns the `EntityId` at a given `index`.
     #[inline]
     pub fn id_at(&self, index: usize) -> Option<EntityId> {
         self.dense.get(index).copied()
     }
+    /// Returns the `EntityId` and component at a given `index` in the `dense` and `data` vectors.
+    /// This index is only valid for this storage and until a removal or sort happens.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.get_by_dense_index(0), Some((entity, &U32(0))));
+    ///     assert_eq!(u32s.get_by_dense_index(1), None);
+    /// });
+    /// ```
+    #[inline]
+    pub fn get_by_dense_index(&self, index: usize) -> Option<(EntityId, &T)> {
+        Some((*self.dense.get(index)?, self.data.get(index)?))
+    }
+    /// Returns the components of all `ids`, in the same order.\
+    /// The same entity can be present multiple times.
+    ///
+    /// For exclusive access to multiple components see [`ViewMut::get_disjoint_mut`](crate::ViewMut::get_disjoint_mut).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(U32(0));
+    /// let entity1 = world.add_entity(());
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     let [a, b, c] = u32s.get_many([entity0, entity1, entity0]);
+    ///
+    ///     assert_eq!(a, Ok(&U32(0)));
+    ///     assert!(b.is_err());
+    ///     assert_eq!(c, Ok(&U32(0)));
+    /// });
+    /// ```
+    #[inline]
+    pub fn get_many<const N: usize>(
+        &self,
+        ids: [EntityId; N],
+    ) -> [Result<&T, error::MissingComponent>; N] {
+        ids.map(|entity| {
+            self.private_get(entity)
+                .ok_or_else(|| error::MissingComponent {
+                    id: entity,
+                    name: type_name::<T>(),
+                })
+        })
+    }
+    /// Applies `f` to `entity`'s component or returns `default` if `entity` doesn't have one.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Health(10));
+    /// let entity1 = world.add_entity(());
+    ///
+    /// world.run(|healths: View<Health>| {
+    ///     assert_eq!(healths.get_or(entity0, |health| health.0, 0), 10);
+    ///     assert_eq!(healths.get_or(entity1, |health| health.0, 0), 0);
+    /// });
+    /// ```
+    #[inline]
+    pub fn get_or<R, F: FnOnce(&T) -> R>(&self, entity: EntityId, f: F, default: R) -> R {
+        match self.private_get(entity) {
+            Some(component) => f(component),
+            None => default,
+        }
+    }
+    /// Applies `f` to `entity`'s component or returns the result of `default` if `entity` doesn't have one.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Name(String);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Name("Alice".to_string()));
+    /// let entity1 = world.add_entity(());
+    ///
+    /// world.run(|names: View<Name>| {
+    ///     let name = |entity| names.get_or_else(entity, |name| name.0.clone(), || format!("{:?}", entity));
+    ///
+    ///     assert_eq!(name(entity0), "Alice");
+    ///     assert_eq!(name(entity1), format!("{:?}", entity1));
+    /// });
+    /// ```
+    #[inline]
+    pub fn get_or_else<R, F: FnOnce(&T) -> R, D: FnOnce() -> R>(
+        &self,
+        entity: EntityId,
+        f: F,
+        default: D,
+    ) -> R {
+        match self.private_get(entity) {
+            Some(component) => f(component),
+            None => default(),
+        }
+    }
+    /// Copies the components and the entities owning them.\
+    /// The copy can be read from other threads without keeping the storage borrowed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq)]
+    /// struct Pos(f32, f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Pos(0.0, 0.0));
+    ///
+    /// let snapshot = world.run(|positions: View<Pos>| positions.snapshot());
+    ///
+    /// world.get::<&mut Pos>(entity).unwrap().0 = 1.0;
+    ///
+    /// assert_eq!(snapshot[entity], Pos(0.0, 0.0));
+    /// ```
+    pub fn snapshot(&self) -> Snapshot<T>
+    where
+        T: Clone,
+    {
+        Snapshot {
+            sparse: self.sparse.clone(),
+            dense: self.dense.clone(),
+            data: self.data.clone(),
+        }
+    }
+
+    /// Clones the storage without its callbacks.
+    pub(crate) fn private_clone(&self, clone: fn(&T) -> T) -> SparseSet<T> {
+        SparseSet {
+            sparse: self.sparse.clone(),
+            dense: self.dense.clone(),
+            data: self.data.iter().map(clone).collect(),
+            last_insert: self.last_insert,
+            last_modified: self.last_modified,
+            insertion_data: self.insertion_data.clone(),
+            modification_data: self.modification_data.clone(),
+            deletion_data: self
+                .deletion_data
+                .iter()
+                .map(|(entity, timestamp, component)| (*entity, *timestamp, clone(component)))
+                .collect(),
+            removal_data: self.removal_data.clone(),
+            is_tracking_insertion: self.is_tracking_insertion,
+            is_tracking_modification: self.is_tracking_modification,
+            is_tracking_deletion: self.is_tracking_deletion,
+            is_tracking_removal: self.is_tracking_removal,
+            on_insertion: None,
+            on_removal: None,
+        }
+    }
 
     /// Sets the on insertion callback.
     pub fn on_insertion(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) {
@@ -401,6 +593,22 @@ impl<T: Component> SparseSet<T> {
         self.last_insert = current;
         self.last_modified = current;
     }
+    /// Removes the *inserted* flag on `entity`'s component.
+    pub(crate) fn private_clear_inserted(&mut self, entity: EntityId) {
+        if let Some(index) = self.index_of(entity) {
+            if let Some(timestamp) = self.insertion_data.get_mut(index) {
+                *timestamp = TrackingTimestamp::origin();
+            }
+        }
+    }
+    /// Removes the *modified* flag on `entity`'s component.
+    pub(crate) fn private_clear_modified(&mut self, entity: EntityId) {
+        if let Some(index) = self.index_of(entity) {
+            if let Some(timestamp) = self.modification_data.get_mut(index) {
+                *timestamp = TrackingTimestamp::origin();
+            }
+        }
+    }
     /// Clear all deletion tracking data.
     pub fn clear_all_deleted(&mut self) {
         self.deletion_data.clear();
@@ -497,6 +705,52 @@ impl<T: Component> SparseSet<T> {
     pub fn is_tracking_removal(&self) -> bool {
         self.is_tracking_removal
     }
+    /// Returns the tracking cycle at which `entity`'s component was inserted.\
+    /// Returns `None` if the storage doesn't track insertion or `entity` doesn't have a component in this storage.
+    ///
+    /// Ticks are comparable across storages of the same [`World`](crate::World).\
+    /// The cycle advances for each system run or borrow, not once per workload, see [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp).
+    pub fn insertion_tick(&self, entity: EntityId) -> Option<u64> {
+        if !self.is_tracking_insertion {
+            return None;
+        }
+
+        self.index_of(entity)
+            .map(|index| unsafe { self.insertion_data.get_unchecked(index) }.get())
+    }
+    /// Returns the tracking cycle at which `entity`'s component was last modified.\
+    /// Returns `None` if the storage doesn't track modification, `entity` doesn't have a component in this storage or it was never modified.
+    ///
+    /// Ticks are comparable across storages of the same [`World`](crate::World).\
+    /// The cycle advances for each system run or borrow, not once per workload, see [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Get, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Modification)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.add_entity(Health(10));
+    ///
+    /// let mut healths = world.borrow::<ViewMut<Health>>().unwrap();
+    /// assert_eq!(healths.modification_tick(entity), None);
+    ///
+    /// (&mut healths).get(entity).unwrap().0 -= 1;
+    /// let tick = healths.modification_tick(entity).unwrap();
+    /// assert!(tick < world.get_tracking_timestamp().get());
+    /// ```
+    pub fn modification_tick(&self, entity: EntityId) -> Option<u64> {
+        if !self.is_tracking_modification {
+            return None;
+        }
+
+        self.index_of(entity)
+            .map(|index| unsafe { self.modification_data.get_unchecked(index) }.get())
+            .filter(|&tick| tick != TrackingTimestamp::origin().get())
+    }
     /// Returns `true` if the storage tracks insertion, deletion or removal.
     pub fn is_tracking_any(&self) -> bool {
         self.is_tracking_insertion()
@@ -504,6 +758,22 @@ impl<T: Component> SparseSet<T> {
             || self.is_tracking_deletion()
             || self.is_tracking_removal()
     }
+    pub(crate) fn is_inserted_or_modified_within(
+        &self,
+        last: TrackingTimestamp,
+        current: TrackingTimestamp,
+    ) -> bool {
+        (self.is_tracking_insertion()
+            && self
+                .insertion_data
+                .iter()
+                .any(|timestamp| timestamp.is_within(last, current)))
+            || (self.is_tracking_modification()
+                && self
+                    .modification_data
+                    .iter()
+                    .any(|timestamp| timestamp.is_within(last, current)))
+    }
     pub(crate) fn check_tracking<Track: Tracking>(&self) -> Result<(), error::GetStorage> {
         if (Track::track_insertion() && !self.is_tracking_insertion())
             || (Track::track_modification() && !self.is_tracking_modification())
@@ -542,6 +812,42 @@ impl<T: Component> SparseSet<T> {
         self.dense.reserve(additional);
         self.data.reserve(additional);
     }
+    /// Releases unused memory, returns the number of bytes freed.\
+    /// The sparse array is truncated after the highest live entity.
+    ///
+    /// The count is best-effort, it doesn't know the allocator's behavior.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entities = (0..1000).map(|i| world.add_entity(U32(i))).collect::<Vec<_>>();
+    /// for entity in entities {
+    ///     world.delete_entity(entity);
+    /// }
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    /// assert!(u32s.shrink_to_fit() > 0);
+    /// assert_eq!(u32s.shrink_to_fit(), 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) -> usize {
+        let before = self.allocated_memory_bytes();
+
+        self.sparse.shrink_to_fit();
+        self.dense.shrink_to_fit();
+        self.data.shrink_to_fit();
+        self.insertion_data.shrink_to_fit();
+        self.modification_data.shrink_to_fit();
+        self.deletion_data.shrink_to_fit();
+        self.removal_data.shrink_to_fit();
+
+        before.saturating_sub(self.allocated_memory_bytes())
+    }
     /// Sorts the `SparseSet` with a comparator function, but may not preserve the order of equal elements.
     pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
         let mut transform: Vec<usize> = (0..self.dense.len()).collect();
@@ -553,6 +859,122 @@ impl<T: Component> SparseSet<T> {
             })
         });
 
+        self.apply_sort(&transform);
+    }
+
+    /// Sorts the `SparseSet` with a comparator function, preserving the order of equal elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut transform: Vec<usize> = (0..self.dense.len()).collect();
+
+        transform.sort_by(|&i, &j| {
+            // SAFE dense and data have the same length
+            compare(unsafe { self.data.get_unchecked(i) }, unsafe {
+                self.data.get_unchecked(j)
+            })
+        });
+
+        self.apply_sort(&transform);
+    }
+
+    /// Sorts the `SparseSet` with a key extraction function, but may not preserve the order of equal elements.
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the `SparseSet` with a key extraction function, preserving the order of equal elements.
+    ///
+    /// Iterating this storage alone or as the smallest storage of a tuple will follow this order.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct ZOrder(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let back = world.add_entity(ZOrder(-1.0));
+    /// let front = world.add_entity(ZOrder(2.0));
+    /// let middle = world.add_entity(ZOrder(0.5));
+    ///
+    /// let mut z_orders = world.borrow::<ViewMut<ZOrder>>().unwrap();
+    /// z_orders.sort_by_key(|z_order| (z_order.0 * 1000.0) as i32);
+    ///
+    /// assert_eq!(z_orders.iter().ids().collect::<Vec<_>>(), [back, middle, front]);
+    /// assert_eq!(z_orders[front].0, 2.0);
+    /// ```
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts both storages once so the entities present in both come first, in the same order.\
+    /// Returns the number of entities present in both storages.
+    ///
+    /// Iterating both storages together will then access their components sequentially.
+    /// Iteration still looks up the sparse arrays, it doesn't know the storages were sorted.\
+    /// This is a one-shot sort, the order isn't maintained.
+    /// Inserting, removing or deleting components can break it, call this method again to restore it.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Position(0.0));
+    /// let entity = world.add_entity((Position(1.0), Velocity(1.0)));
+    ///
+    /// let (mut positions, mut velocities) = world
+    ///     .borrow::<(ViewMut<Position>, ViewMut<Velocity>)>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(positions.co_locate_with(&mut velocities), 1);
+    /// assert_eq!(positions.index_of(entity), Some(0));
+    /// assert_eq!(velocities.index_of(entity), Some(0));
+    /// ```
+    pub fn co_locate_with<U: Component>(&mut self, other: &mut SparseSet<U>) -> usize {
+        let shared: Vec<EntityId> = self
+            .dense
+            .iter()
+            .copied()
+            .filter(|&entity| other.contains(entity))
+            .collect();
+
+        self.move_to_front(&shared);
+        other.move_to_front(&shared);
+
+        shared.len()
+    }
+
+    /// Moves `entities`' components to the front of the storage, in the given order.\
+    /// The other components keep their relative order.
+    ///
+    /// All `entities` have to be present in the storage.
+    fn move_to_front(&mut self, entities: &[EntityId]) {
+        let mut is_front = vec![false; self.dense.len()];
+        let mut transform = Vec::with_capacity(self.dense.len());
+
+        for &entity in entities {
+            let index = self.index_of(entity).unwrap();
+
+            is_front[index] = true;
+            transform.push(index);
+        }
+
+        transform.extend((0..self.dense.len()).filter(|&index| !is_front[index]));
+
+        self.apply_sort(&transform);
+    }
+
+    /// Moves the component at `transform[i]` to `i`, tracking data included, and updates the sparse array.
+    fn apply_sort(&mut self, transform: &[usize]) {
         let mut pos;
         for i in 0..transform.len() {
             // SAFE we're in bound
@@ -563,6 +985,12 @@ impl<T: Component> SparseSet<T> {
             }
             self.dense.swap(i, pos);
             self.data.swap(i, pos);
+            if self.is_tracking_insertion {
+                self.insertion_data.swap(i, pos);
+            }
+            if self.is_tracking_modification {
+                self.modification_data.swap(i, pos);
+            }
         }
 
         for (i, id) in self.dense.iter().enumerate() {
@@ -657,6 +1085,69 @@ impl<T: Component> SparseSet<T> {
         }
     }
 
+    /// Applies the given function `f` to the entities `a`, `b` and `c`.\
+    /// The three entities shouldn't point to the same component.
+    ///
+    /// Missing components are reported first, in `a`, `b`, `c` order.
+    pub(crate) fn private_apply3_mut<R, F: FnOnce(&mut T, &mut T, &mut T) -> R>(
+        &mut self,
+        a: EntityId,
+        b: EntityId,
+        c: EntityId,
+        f: F,
+        current: TrackingTimestamp,
+    ) -> Result<R, error::Apply> {
+        let a_index = self.index_of(a).ok_or(error::Apply::MissingComponent(a))?;
+        let b_index = self.index_of(b).ok_or(error::Apply::MissingComponent(b))?;
+        let c_index = self.index_of(c).ok_or(error::Apply::MissingComponent(c))?;
+
+        if a_index == b_index || a_index == c_index || b_index == c_index {
+            return Err(error::Apply::IdenticalIds);
+        }
+
+        if self.is_tracking_modification {
+            self.modification_data[a_index] = current;
+            self.modification_data[b_index] = current;
+            self.modification_data[c_index] = current;
+        }
+
+        let a = unsafe { &mut *self.data.as_mut_ptr().add(a_index) };
+        let b = unsafe { &mut *self.data.as_mut_ptr().add(b_index) };
+        let c = unsafe { &mut *self.data.as_mut_ptr().add(c_index) };
+
+        Ok(f(a, b, c))
+    }
+    pub(crate) fn private_get_disjoint_mut<const N: usize>(
+        &mut self,
+        ids: [EntityId; N],
+        current: TrackingTimestamp,
+    ) -> Result<[&mut T; N], error::Apply> {
+        let mut indices = [0; N];
+
+        for (index, id) in indices.iter_mut().zip(ids) {
+            *index = self
+                .index_of(id)
+                .ok_or(error::Apply::MissingComponent(id))?;
+        }
+
+        for (i, index) in indices.iter().enumerate() {
+            if indices[..i].contains(index) {
+                return Err(error::Apply::IdenticalIds);
+            }
+        }
+
+        if self.is_tracking_modification {
+            for &index in &indices {
+                self.modification_data[index] = current;
+            }
+        }
+
+        let data = self.data.as_mut_ptr();
+
+        // SAFE indices are in bounds and disjoint
+        Ok(indices.map(|index| unsafe { &mut *data.add(index) }))
+    }
+
     /// Deletes all components in this storage.
     pub(crate) fn private_clear(&mut self, current: TrackingTimestamp) {
         for &id in &self.dense {
@@ -731,6 +1222,31 @@ impl<T: Component> SparseSet<T> {
         }
     }
 
+    pub(crate) fn private_update_or_remove<F: FnOnce(Mut<'_, T>) -> bool>(
+        &mut self,
+        entity: EntityId,
+        f: F,
+        current: TrackingTimestamp,
+    ) -> Option<T> {
+        let index = self.index_of(entity)?;
+
+        let component = Mut {
+            flag: self
+                .is_tracking_modification
+                .then(|| unsafe { self.modification_data.get_unchecked_mut(index) }),
+            current,
+            data: unsafe { self.data.get_unchecked_mut(index) },
+            compare: T::MODIFICATION_COMPARE,
+            previous: None,
+        };
+
+        if f(component) {
+            None
+        } else {
+            self.dyn_remove(entity, current)
+        }
+    }
+
     pub(crate) fn private_retain_mut<F: FnMut(EntityId, Mut<'_, T>) -> bool>(
         &mut self,
         current: TrackingTimestamp,
@@ -745,6 +1261,8 @@ impl<T: Component> SparseSet<T> {
                 flag: self.modification_data.get_mut(i),
                 current,
                 data: unsafe { self.data.get_unchecked_mut(i) },
+                compare: T::MODIFICATION_COMPARE,
+                previous: None,
             };
 
             if !f(eid, component) {
@@ -760,6 +1278,10 @@ impl<T: Ord + Component> SparseSet<T> {
     pub fn sort_unstable(&mut self) {
         self.sort_unstable_by(Ord::cmp)
     }
+    /// Sorts the `SparseSet`, preserving the order of equal elements.
+    pub fn sort(&mut self) {
+        self.sort_by(Ord::cmp)
+    }
 }
 
 impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
@@ -768,6 +1290,10 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
         self.dyn_delete(entity, current);
     }
     #[inline]
+    fn strip(&mut self, entity: EntityId, current: TrackingTimestamp) {
+        self.dyn_remove(entity, current);
+    }
+    #[inline]
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
@@ -777,9 +1303,18 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(self.private_memory_usage())
     }
+    fn memory_breakdown(&self) -> Option<SparseSetMemoryUsage> {
+        Some(self.detailed_memory_usage())
+    }
+    fn shrink_to_fit(&mut self) -> usize {
+        SparseSet::shrink_to_fit(self)
+    }
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.len())
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -810,6 +1345,49 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
             let _ = other_sparse_set.insert(to, component, other_current);
         }
     }
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        other_current: TrackingTimestamp,
+    ) -> bool {
+        let Some(component) = self.private_get(from) else {
+            return true;
+        };
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        let other_sparse_set = other_all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new);
+
+        let _ = other_sparse_set.insert(to, clone(component), other_current);
+
+        true
+    }
+    fn is_component_clonable(&self, entity: EntityId) -> bool {
+        T::CLONE.is_some() || !self.contains(entity)
+    }
+    fn clone_component(&mut self, from: EntityId, to: EntityId, current: TrackingTimestamp) {
+        if let Some((component, clone)) = self.private_get(from).zip(T::CLONE) {
+            let component = clone(component);
+            let _ = self.insert(to, component, current);
+        }
+    }
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        other_all_storages.insert_cloned_storage(storage_id, self.private_clone(clone));
+
+        true
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -819,6 +1397,10 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
         self.dyn_delete(entity, current);
     }
     #[inline]
+    fn strip(&mut self, entity: EntityId, current: TrackingTimestamp) {
+        self.dyn_remove(entity, current);
+    }
+    #[inline]
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
@@ -828,9 +1410,18 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(self.private_memory_usage())
     }
+    fn memory_breakdown(&self) -> Option<SparseSetMemoryUsage> {
+        Some(self.detailed_memory_usage())
+    }
+    fn shrink_to_fit(&mut self) -> usize {
+        SparseSet::shrink_to_fit(self)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -861,6 +1452,51 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
             let _ = other_sparse_set.insert(to, component, other_current);
         }
     }
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        other_current: TrackingTimestamp,
+    ) -> bool {
+        let Some(component) = self.private_get(from) else {
+            return true;
+        };
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        let other_sparse_set = other_all_storages.exclusive_storage_or_insert_non_send_mut(
+            StorageId::of::<NonSend<SparseSet<T>>>(),
+            || NonSend(SparseSet::<T>::new()),
+        );
+
+        let _ = other_sparse_set.insert(to, clone(component), other_current);
+
+        true
+    }
+    fn is_component_clonable(&self, entity: EntityId) -> bool {
+        T::CLONE.is_some() || !self.contains(entity)
+    }
+    fn clone_component(&mut self, from: EntityId, to: EntityId, current: TrackingTimestamp) {
+        if let Some((component, clone)) = self.private_get(from).zip(T::CLONE) {
+            let component = clone(component);
+            let _ = self.insert(to, component, current);
+        }
+    }
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        other_all_storages.insert_cloned_storage(storage_id, NonSend(self.private_clone(clone)));
+
+        true
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -870,6 +1506,10 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
         self.dyn_delete(entity, current);
     }
     #[inline]
+    fn strip(&mut self, entity: EntityId, current: TrackingTimestamp) {
+        self.dyn_remove(entity, current);
+    }
+    #[inline]
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
@@ -879,9 +1519,18 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(self.private_memory_usage())
     }
+    fn memory_breakdown(&self) -> Option<SparseSetMemoryUsage> {
+        Some(self.detailed_memory_usage())
+    }
+    fn shrink_to_fit(&mut self) -> usize {
+        SparseSet::shrink_to_fit(self)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -912,6 +1561,51 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
             let _ = other_sparse_set.insert(to, component, other_current);
         }
     }
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        other_current: TrackingTimestamp,
+    ) -> bool {
+        let Some(component) = self.private_get(from) else {
+            return true;
+        };
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        let other_sparse_set = other_all_storages.exclusive_storage_or_insert_non_sync_mut(
+            StorageId::of::<NonSync<SparseSet<T>>>(),
+            || NonSync(SparseSet::<T>::new()),
+        );
+
+        let _ = other_sparse_set.insert(to, clone(component), other_current);
+
+        true
+    }
+    fn is_component_clonable(&self, entity: EntityId) -> bool {
+        T::CLONE.is_some() || !self.contains(entity)
+    }
+    fn clone_component(&mut self, from: EntityId, to: EntityId, current: TrackingTimestamp) {
+        if let Some((component, clone)) = self.private_get(from).zip(T::CLONE) {
+            let component = clone(component);
+            let _ = self.insert(to, component, current);
+        }
+    }
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        other_all_storages.insert_cloned_storage(storage_id, NonSync(self.private_clone(clone)));
+
+        true
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -921,6 +1615,10 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
         self.dyn_delete(entity, current);
     }
     #[inline]
+    fn strip(&mut self, entity: EntityId, current: TrackingTimestamp) {
+        self.dyn_remove(entity, current);
+    }
+    #[inline]
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
@@ -930,9 +1628,18 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         Some(self.private_memory_usage())
     }
+    fn memory_breakdown(&self) -> Option<SparseSetMemoryUsage> {
+        Some(self.detailed_memory_usage())
+    }
+    fn shrink_to_fit(&mut self) -> usize {
+        SparseSet::shrink_to_fit(self)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
     fn clear_all_removed_and_deleted(&mut self) {
         self.deletion_data.clear();
         self.removal_data.clear();
@@ -964,6 +1671,52 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
             let _ = other_sparse_set.insert(to, component, other_current);
         }
     }
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        other_current: TrackingTimestamp,
+    ) -> bool {
+        let Some(component) = self.private_get(from) else {
+            return true;
+        };
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        let other_sparse_set = other_all_storages.exclusive_storage_or_insert_non_send_sync_mut(
+            StorageId::of::<NonSendSync<SparseSet<T>>>(),
+            || NonSendSync(SparseSet::<T>::new()),
+        );
+
+        let _ = other_sparse_set.insert(to, clone(component), other_current);
+
+        true
+    }
+    fn is_component_clonable(&self, entity: EntityId) -> bool {
+        T::CLONE.is_some() || !self.contains(entity)
+    }
+    fn clone_component(&mut self, from: EntityId, to: EntityId, current: TrackingTimestamp) {
+        if let Some((component, clone)) = self.private_get(from).zip(T::CLONE) {
+            let component = clone(component);
+            let _ = self.insert(to, component, current);
+        }
+    }
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        other_all_storages
+            .insert_cloned_storage(storage_id, NonSendSync(self.private_clone(clone)));
+
+        true
+    }
 }
 
 #[cfg(test)]
@@ -979,7 +1732,7 @@ mod tests {
         type Tracking = crate::track::Untracked;
     }
 
-    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
     struct I32(i32);
 
     impl Component for I32 {
@@ -1231,7 +1984,9 @@ mod tests {
 
         let mut drain = sparse_set.private_drain(TrackingTimestamp::new(0));
 
+        assert_eq!(drain.len(), 2);
         assert_eq!(drain.next(), Some(I32(0)));
+        assert_eq!(drain.len(), 1);
         assert_eq!(drain.next(), Some(I32(1)));
         assert_eq!(drain.next(), None);
 
@@ -1246,6 +2001,34 @@ mod tests {
         assert_eq!(sparse_set.removal_data.len(), 2);
     }
 
+    #[test]
+    fn snapshot() {
+        let mut sparse_set = SparseSet::new();
+
+        sparse_set
+            .insert(EntityId::new(0), I32(0), TrackingTimestamp::new(0))
+            .assert_inserted();
+        sparse_set
+            .insert(EntityId::new(1), I32(1), TrackingTimestamp::new(0))
+            .assert_inserted();
+
+        let snapshot = sparse_set.snapshot();
+
+        sparse_set.actual_remove(EntityId::new(0));
+        sparse_set
+            .insert(
+                EntityId::new_from_index_and_gen(0, 1),
+                I32(2),
+                TrackingTimestamp::new(0),
+            )
+            .assert_inserted();
+
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.get(EntityId::new(0)), Some(&I32(0)));
+        assert_eq!(snapshot.get(EntityId::new_from_index_and_gen(0, 1)), None);
+        assert_eq!(snapshot[EntityId::new(1)], I32(1));
+    }
+
     #[test]
     fn drain_with_id() {
         let mut sparse_set = SparseSet::new();
@@ -1350,6 +2133,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn stable_sort() {
+        let mut array = SparseSet::new();
+
+        for i in 0..30 {
+            let mut entity_id = EntityId::zero();
+            entity_id.set_index(i);
+            array
+                .insert(entity_id, I32(i as i32 % 3), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+
+        array.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for window in array.dense.windows(2).zip(array.data.windows(2)) {
+            let (ids, values) = window;
+            assert!(values[0] < values[1] || (values[0] == values[1] && ids[0] < ids[1]));
+        }
+        for i in 0..30 {
+            let mut entity_id = EntityId::zero();
+            entity_id.set_index(i);
+            assert_eq!(array.private_get(entity_id), Some(&I32(i as i32 % 3)));
+        }
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let mut array = SparseSet::new();
+
+        for i in 0..30 {
+            let mut entity_id = EntityId::zero();
+            entity_id.set_index(i);
+            array
+                .insert(entity_id, I32(i as i32 % 3), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+
+        array.sort_by_key(|i32| core::cmp::Reverse(i32.0));
+
+        for window in array.dense.windows(2).zip(array.data.windows(2)) {
+            let (ids, values) = window;
+            assert!(values[0] > values[1] || (values[0] == values[1] && ids[0] < ids[1]));
+        }
+        for i in 0..30 {
+            let mut entity_id = EntityId::zero();
+            entity_id.set_index(i);
+            assert_eq!(array.private_get(entity_id), Some(&I32(i as i32 % 3)));
+        }
+    }
+
+    #[test]
+    fn co_locate_with() {
+        let mut array1 = SparseSet::new();
+        let mut array2 = SparseSet::new();
+
+        for i in 0..10 {
+            array1
+                .insert(EntityId::new(i), I32(i as i32), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+        for i in (0..10).rev().step_by(3) {
+            array2
+                .insert(EntityId::new(i), I32(i as i32), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+        array2
+            .insert(EntityId::new(20), I32(20), TrackingTimestamp::new(0))
+            .assert_inserted();
+
+        assert_eq!(array1.co_locate_with(&mut array2), 4);
+
+        let shared = [0, 3, 6, 9].map(EntityId::new);
+        assert_eq!(&array1.dense[..4], &shared);
+        assert_eq!(&array2.dense[..4], &shared);
+        assert_eq!(array2.dense[4], EntityId::new(20));
+        for i in 0..10 {
+            assert_eq!(array1.private_get(EntityId::new(i)), Some(&I32(i as i32)));
+        }
+        assert_eq!(array2.private_get(EntityId::new(20)), Some(&I32(20)));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut sparse_set = SparseSet::new();
+
+        for i in 0..100 {
+            sparse_set
+                .insert(EntityId::new(i), I32(i as i32), TrackingTimestamp::new(0))
+                .assert_inserted();
+        }
+        for i in 1..100 {
+            sparse_set.dyn_delete(EntityId::new(i), TrackingTimestamp::new(0));
+        }
+
+        assert!(sparse_set.shrink_to_fit() > 0);
+        assert_eq!(sparse_set.sparse.len(), 1);
+        assert!(sparse_set.dense.capacity() < 100);
+        assert_eq!(sparse_set.private_get(EntityId::new(0)), Some(&I32(0)));
+
+        sparse_set
+            .insert(EntityId::new(99), I32(99), TrackingTimestamp::new(0))
+            .assert_inserted();
+        assert_eq!(sparse_set.private_get(EntityId::new(99)), Some(&I32(99)));
+    }
+
     #[test]
     fn debug() {
         let mut sparse_set = SparseSet::new();
@@ -0,0 +1,66 @@
+use crate::entity_id::EntityId;
+use crate::sparse_set::{SparseArray, BUCKET_SIZE};
+use alloc::vec::Vec;
+
+/// Owned copy of a storage at a point in time.
+///
+/// Components can be looked up by `EntityId` and the copy can be sent to other threads,
+/// later modifications of the storage are not reflected.
+#[derive(Clone)]
+pub struct Snapshot<T> {
+    pub(super) sparse: SparseArray<EntityId, BUCKET_SIZE>,
+    pub(super) dense: Vec<EntityId>,
+    pub(super) data: Vec<T>,
+}
+
+impl<T> Snapshot<T> {
+    /// Returns `entity`'s component.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<&T> {
+        self.index_of(entity)
+            .map(|index| unsafe { self.data.get_unchecked(index) })
+    }
+    /// Returns `true` if `entity` had a component.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.index_of(entity).is_some()
+    }
+    /// Returns the number of components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+    /// Returns `true` if there are no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    /// Returns the components, in the storage order at the time of the snapshot.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    /// Returns an iterator over the components and the entity owning them.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        self.dense.iter().copied().zip(&self.data)
+    }
+    fn index_of(&self, entity: EntityId) -> Option<usize> {
+        self.sparse.get(entity).and_then(|sparse_entity| {
+            if entity.gen() == sparse_entity.gen() {
+                Some(sparse_entity.uindex())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<T> core::ops::Index<EntityId> for Snapshot<T> {
+    type Output = T;
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, entity: EntityId) -> &Self::Output {
+        self.get(entity).unwrap()
+    }
+}
@@ -7,6 +7,7 @@ use core::mem::size_of;
 /// Internal part of a [`SparseSet`].
 ///
 /// [`SparseSet`]: crate::sparse_set::SparseSet
+#[derive(Clone)]
 pub struct SparseArray<T, const N: usize>(Vec<Option<Box<[T; N]>>>);
 
 impl<T, const N: usize> SparseArray<T, N> {
@@ -101,6 +102,22 @@ impl<const N: usize> SparseArray<EntityId, N> {
             None => unreachable_unchecked(),
         }
     }
+    /// Frees buckets without any live entry and truncates the trailing empty ones.
+    pub(super) fn shrink_to_fit(&mut self) {
+        for bucket in &mut self.0 {
+            if let Some(array) = bucket {
+                if array.iter().all(|entity| entity.is_dead()) {
+                    *bucket = None;
+                }
+            }
+        }
+
+        while let Some(None) = self.0.last() {
+            self.0.pop();
+        }
+
+        self.0.shrink_to_fit();
+    }
     #[inline]
     #[allow(missing_docs)]
     pub fn contains(&self, entity: EntityId) -> bool {
@@ -0,0 +1,136 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::reserve::BulkEntityIter;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+#[cfg(doc)]
+use crate::world::World;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::iter::IntoIterator;
+
+/// Trait used as bound for [`World::spawn_columns`] and [`AllStorages::spawn_columns`].
+///
+/// Implemented for tuples of iterators, each iterator yielding the components of a single storage.
+pub trait SpawnColumns {
+    /// See [`World::spawn_columns`] and [`AllStorages::spawn_columns`].
+    fn spawn_columns(
+        self,
+        all_storages: &mut AllStorages,
+    ) -> Result<BulkEntityIter<'_>, error::SpawnColumns>;
+}
+
+/// Adds `new_entities` to `sparse_set`, their components have to already be in `data`.
+fn extend_sparse_set<T: Component>(
+    sparse_set: &mut SparseSet<T>,
+    new_entities: &[EntityId],
+    current: TrackingTimestamp,
+) {
+    let old_len = sparse_set.dense.len();
+
+    sparse_set.dense.extend_from_slice(new_entities);
+
+    if sparse_set.is_tracking_insertion() {
+        sparse_set
+            .insertion_data
+            .extend(new_entities.iter().map(|_| current));
+    }
+    if sparse_set.is_tracking_modification() {
+        sparse_set
+            .modification_data
+            .extend(new_entities.iter().map(|_| TrackingTimestamp::origin()));
+    }
+
+    let SparseSet { sparse, dense, .. } = sparse_set;
+
+    sparse.bulk_allocate(dense[old_len], dense[dense.len() - 1]);
+    for (i, &entity) in dense[old_len..].iter().enumerate() {
+        unsafe {
+            *sparse.get_mut_unchecked(entity) = EntityId::new((old_len + i) as u64);
+        }
+    }
+}
+
+macro_rules! impl_spawn_columns {
+    ($(($type: ident, $column: ident, $index: tt))+) => {
+        impl<$($type: IntoIterator),+> SpawnColumns for ($($type,)+)
+        where
+            $(<$type as IntoIterator>::Item: Send + Sync + Component,)+
+        {
+            fn spawn_columns(self, all_storages: &mut AllStorages) -> Result<BulkEntityIter<'_>, error::SpawnColumns> {
+                let storage_ids = [$(StorageId::of::<SparseSet<<$type as IntoIterator>::Item>>()),+];
+                let names = [$(type_name::<<$type as IntoIterator>::Item>()),+];
+
+                for (i, storage_id) in storage_ids.iter().enumerate() {
+                    if storage_ids[..i].contains(storage_id) {
+                        return Err(error::SpawnColumns::DuplicateComponent(names[i]));
+                    }
+                }
+
+                $(
+                    let $column: Vec<<$type as IntoIterator>::Item> = self.$index.into_iter().collect();
+                )+
+
+                let counts = [$($column.len()),+];
+                let expected = counts[0];
+
+                if let Some(column) = counts.iter().position(|&count| count != expected) {
+                    return Err(error::SpawnColumns::MismatchedLength {
+                        expected,
+                        found: counts[column],
+                        column,
+                    });
+                }
+
+                let current = all_storages.get_current();
+                let mut entities = all_storages.entities_mut().unwrap();
+                let entities_len = entities.data.len();
+                let new_entities = entities.bulk_generate(expected);
+
+                $(
+                    let mut sparse_set = all_storages.custom_storage_or_insert_mut(SparseSet::<<$type as IntoIterator>::Item>::new).unwrap();
+
+                    if expected > 0 {
+                        sparse_set.data.extend($column);
+                        extend_sparse_set(&mut *sparse_set, new_entities, current);
+                    }
+
+                    drop(sparse_set);
+                )+
+
+                drop(entities);
+
+                let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
+
+                Ok(BulkEntityIter {
+                    iter: entities.data[entities_len..].iter().copied(),
+                    slice: &entities.data[entities_len..],
+                })
+            }
+        }
+    };
+}
+
+macro_rules! spawn_columns {
+    ($(($type: ident, $column: ident, $index: tt))+; ($type1: ident, $column1: ident, $index1: tt) $(($queue_type: ident, $queue_column: ident, $queue_index: tt))*) => {
+        impl_spawn_columns![$(($type, $column, $index))+];
+        spawn_columns![$(($type, $column, $index))+ ($type1, $column1, $index1); $(($queue_type, $queue_column, $queue_index))*];
+    };
+    ($(($type: ident, $column: ident, $index: tt))+;) => {
+        impl_spawn_columns![$(($type, $column, $index))+];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+spawn_columns![(A, column0, 0); (B, column1, 1) (C, column2, 2) (D, column3, 3) (E, column4, 4) (F, column5, 5) (G, column6, 6) (H, column7, 7) (I, column8, 8) (J, column9, 9)];
+#[cfg(feature = "extended_tuple")]
+spawn_columns![
+    (A, column0, 0); (B, column1, 1) (C, column2, 2) (D, column3, 3) (E, column4, 4) (F, column5, 5) (G, column6, 6) (H, column7, 7) (I, column8, 8) (J, column9, 9)
+    (K, column10, 10) (L, column11, 11) (M, column12, 12) (N, column13, 13) (O, column14, 14) (P, column15, 15) (Q, column16, 16) (R, column17, 17) (S, column18, 18) (T, column19, 19)
+    (U, column20, 20) (V, column21, 21) (W, column22, 22) (X, column23, 23) (Y, column24, 24) (Z, column25, 25) (AA, column26, 26) (BB, column27, 27) (CC, column28, 28) (DD, column29, 29)
+    (EE, column30, 30) (FF, column31, 31)
+];
@@ -19,10 +19,13 @@ pub struct FullRawWindow<'a, T> {
     pub(crate) last_insertion: TrackingTimestamp,
     pub(crate) last_modification: TrackingTimestamp,
     pub(crate) current: TrackingTimestamp,
+    pub(crate) is_tracking_insertion: bool,
+    pub(crate) is_tracking_modification: bool,
     _phantom: PhantomData<&'a T>,
 }
 
-unsafe impl<T: Send + Component> Send for FullRawWindow<'_, T> {}
+// FullRawWindow hands out shared references, they can only cross threads if T is Sync
+unsafe impl<T: Sync + Component> Send for FullRawWindow<'_, T> {}
 
 impl<'w, T: Component> FullRawWindow<'w, T> {
     #[inline]
@@ -42,6 +45,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             last_insertion: view.last_insertion,
             last_modification: view.last_modification,
             current: view.current,
+            is_tracking_insertion: view.is_tracking_insertion(),
+            is_tracking_modification: view.is_tracking_modification(),
             _phantom: PhantomData,
         }
     }
@@ -76,6 +81,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
                 last_insertion,
                 last_modification,
                 current,
+                is_tracking_insertion: sparse_set.is_tracking_insertion(),
+                is_tracking_modification: sparse_set.is_tracking_modification(),
                 _phantom: PhantomData,
             },
             all_borrow,
@@ -100,6 +107,8 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             last_insertion: view.last_insertion,
             last_modification: view.last_modification,
             current: view.current,
+            is_tracking_insertion: view.is_tracking_insertion(),
+            is_tracking_modification: view.is_tracking_modification(),
             _phantom: PhantomData,
         }
     }
@@ -155,6 +164,8 @@ impl<T: Component> Clone for FullRawWindow<'_, T> {
             last_insertion: self.last_insertion,
             last_modification: self.last_modification,
             current: self.current,
+            is_tracking_insertion: self.is_tracking_insertion,
+            is_tracking_modification: self.is_tracking_modification,
             _phantom: PhantomData,
         }
     }
@@ -7,7 +7,7 @@ pub(crate) use sbox::SBox;
 
 use crate::all_storages::AllStorages;
 use crate::entity_id::EntityId;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{SparseSetMemoryUsage, StorageMemoryUsage};
 use crate::sparse_set::SparseArray;
 use crate::tracking::TrackingTimestamp;
 use alloc::borrow::Cow;
@@ -43,6 +43,12 @@ pub trait Storage: SizedAny {
     #[inline]
     #[allow(unused_variables)]
     fn delete(&mut self, entity: EntityId, current: TrackingTimestamp) {}
+    /// Removes an entity's component from this storage without deleting the entity.\
+    /// Defaults to [`Storage::delete`], storages tracking removal should record it.
+    #[inline]
+    fn strip(&mut self, entity: EntityId, current: TrackingTimestamp) {
+        self.delete(entity, current);
+    }
     /// Deletes all components of this storage.
     #[inline]
     #[allow(unused_variables)]
@@ -51,6 +57,14 @@ pub trait Storage: SizedAny {
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         None
     }
+    /// Returns how much memory each part of this storage uses.
+    fn memory_breakdown(&self) -> Option<SparseSetMemoryUsage> {
+        None
+    }
+    /// Releases unused memory, returns the number of bytes freed.
+    fn shrink_to_fit(&mut self) -> usize {
+        0
+    }
     /// Returns the storage's name.
     fn name(&self) -> Cow<'static, str> {
         core::any::type_name::<Self>().into()
@@ -66,6 +80,10 @@ pub trait Storage: SizedAny {
     fn is_empty(&self) -> bool {
         false
     }
+    /// Returns the number of components in the storage.
+    fn len(&self) -> Option<usize> {
+        None
+    }
     /// Clear all deletion and removal tracking data.
     fn clear_all_removed_and_deleted(&mut self) {}
     /// Clear all deletion and removal tracking data older than some timestamp.
@@ -86,4 +104,39 @@ pub trait Storage: SizedAny {
         other_current: TrackingTimestamp,
     ) {
     }
+    /// Clones a component from a `World` to another.\
+    /// Returns `false` if `from` has a component in this storage but it can't be cloned.
+    #[inline]
+    #[allow(unused_variables)]
+    fn clone_component_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        from: EntityId,
+        to: EntityId,
+        other_current: TrackingTimestamp,
+    ) -> bool {
+        true
+    }
+    /// Returns `false` if `entity` has a component in this storage but it can't be cloned.
+    #[inline]
+    #[allow(unused_variables)]
+    fn is_component_clonable(&self, entity: EntityId) -> bool {
+        true
+    }
+    /// Clones `from`'s component to `to`, in this storage.\
+    /// Does nothing if `from` doesn't have a component or it can't be cloned.
+    #[inline]
+    #[allow(unused_variables)]
+    fn clone_component(&mut self, from: EntityId, to: EntityId, current: TrackingTimestamp) {}
+    /// Copies this storage to another `World` under `storage_id`.\
+    /// Returns `false` if the storage can't be cloned.
+    #[inline]
+    #[allow(unused_variables)]
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        false
+    }
 }
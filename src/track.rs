@@ -43,7 +43,11 @@ pub struct ModificationAndDeletionAndRemoval;
 pub struct Deletion;
 #[allow(missing_docs)]
 pub struct DeletionAndRemoval;
-#[allow(missing_docs)]
+/// Records the id of components removed with `remove`, the component itself is returned to the caller and not kept.\
+/// To keep the value of components going away, delete them with deletion tracking, [`ViewMut::deleted`](crate::ViewMut::deleted) yields them.
+///
+/// Removed ids and deleted components are kept until cleared with [`clear_all_removed`](crate::sparse_set::SparseSet::clear_all_removed),
+/// [`clear_all_deleted`](crate::sparse_set::SparseSet::clear_all_deleted) or their `older_than_timestamp` variants, the buffers grow with each removal or deletion otherwise.
 pub struct Removal;
 #[allow(missing_docs)]
 pub struct All;
@@ -1,7 +1,11 @@
 mod iterator_wrapper;
+mod modification_compare;
+mod tuple_changed;
 mod tuple_track;
 
 pub use iterator_wrapper::{Inserted, InsertedOrModified, Modified};
+pub use modification_compare::ModificationCompare;
+pub use tuple_changed::TupleChanged;
 pub use tuple_track::TupleTrack;
 
 use crate::component::Component;
@@ -197,8 +201,9 @@ impl TrackingTimestamp {
         TrackingTimestamp::new(0)
     }
 
+    /// Returns the tracking cycle of this timestamp.
     #[inline]
-    pub(crate) fn get(self) -> u64 {
+    pub fn get(self) -> u64 {
         self.0
     }
 
@@ -0,0 +1,64 @@
+#[cfg(doc)]
+use crate::component::Component;
+#[cfg(doc)]
+use crate::r#mut::Mut;
+use alloc::boxed::Box;
+
+/// Functions used to only flag components as modified when their value changed.
+///
+/// Set with [`Component::MODIFICATION_COMPARE`] or derive it with `#[shipyard(compare)]`.\
+/// The component is cloned on the first mutable access through a [`Mut`] and compared to its new value when the [`Mut`] is dropped.
+/// The clone is boxed and only lives as long as the [`Mut`].
+///
+/// [`World::get`](crate::World::get) and [`AllStorages::get`](crate::AllStorages::get) don't compare, they flag any mutable access.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, Get, IntoIter, ViewMut, World};
+///
+/// #[derive(Component, Clone, PartialEq)]
+/// #[track(Modification)]
+/// #[shipyard(compare)]
+/// struct Position(f32, f32);
+///
+/// let mut world = World::new();
+///
+/// let entity0 = world.add_entity(Position(0.0, 0.0));
+/// let entity1 = world.add_entity(Position(1.0, 1.0));
+///
+/// let mut positions = world.borrow::<ViewMut<Position>>().unwrap();
+///
+/// for mut position in (&mut positions).iter() {
+///     position.0 += 0.0;
+/// }
+/// (&mut positions).get(entity1).unwrap().1 = 5.0;
+///
+/// assert!(!positions.is_modified(entity0));
+/// assert!(positions.is_modified(entity1));
+/// ```
+pub struct ModificationCompare<T: ?Sized> {
+    pub(crate) clone: fn(&T) -> Box<T>,
+    pub(crate) eq: fn(&T, &T) -> bool,
+}
+
+impl<T: Clone + PartialEq> ModificationCompare<T> {
+    /// Compares components with [`PartialEq`], [`Clone`] is used to keep a copy of their previous value.
+    pub const fn new() -> ModificationCompare<T> {
+        ModificationCompare {
+            clone: boxed_clone::<T>,
+            eq: T::eq,
+        }
+    }
+}
+
+fn boxed_clone<T: Clone>(value: &T) -> Box<T> {
+    Box::new(value.clone())
+}
+
+impl<T: ?Sized> Clone for ModificationCompare<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ModificationCompare<T> {}
@@ -0,0 +1,69 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+use crate::error;
+#[cfg(doc)]
+use crate::scheduler::WorkloadModificator;
+use crate::sparse_set::SparseSet;
+use crate::tracking::TrackingTimestamp;
+
+/// Trait used as bound for [`WorkloadModificator::run_if_changed`].
+pub trait TupleChanged {
+    /// Returns `true` if any component was inserted or modified between `last` and `current`.
+    fn is_inserted_or_modified(
+        all_storages: &AllStorages,
+        last: TrackingTimestamp,
+        current: TrackingTimestamp,
+    ) -> bool;
+}
+
+impl<T: Send + Sync + Component> TupleChanged for T {
+    #[inline]
+    fn is_inserted_or_modified(
+        all_storages: &AllStorages,
+        last: TrackingTimestamp,
+        current: TrackingTimestamp,
+    ) -> bool {
+        match all_storages.custom_storage::<SparseSet<T>>() {
+            Ok(sparse_set) => sparse_set.is_inserted_or_modified_within(last, current),
+            Err(error::GetStorage::MissingStorage { .. }) => false,
+            Err(_) => true,
+        }
+    }
+}
+
+macro_rules! impl_changed {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component,)+> TupleChanged for ($($type,)+) {
+            #[inline]
+            fn is_inserted_or_modified(
+                all_storages: &AllStorages,
+                last: TrackingTimestamp,
+                current: TrackingTimestamp,
+            ) -> bool {
+                $(
+                    <$type as TupleChanged>::is_inserted_or_modified(all_storages, last, current)
+                )||+
+            }
+        }
+    };
+}
+
+macro_rules! changed {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_changed![$(($type, $index))*];
+        changed![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_changed![$(($type, $index))*];
+    }
+}
+
+#[cfg(not(feature = "extended_tuple"))]
+changed![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
+#[cfg(feature = "extended_tuple")]
+changed![
+    (A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)
+    (K, 10) (L, 11) (M, 12) (N, 13) (O, 14) (P, 15) (Q, 16) (R, 17) (S, 18) (T, 19)
+    (U, 20) (V, 21) (W, 22) (X, 23) (Y, 24) (Z, 25) (AA, 26) (BB, 27) (CC, 28) (DD, 29)
+    (EE, 30) (FF, 31)
+];
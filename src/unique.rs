@@ -1,6 +1,7 @@
+use crate::all_storages::AllStorages;
 use crate::component::Unique;
 use crate::memory_usage::StorageMemoryUsage;
-use crate::storage::Storage;
+use crate::storage::{Storage, StorageId};
 use crate::tracking::TrackingTimestamp;
 use core::any::type_name;
 use core::mem::size_of;
@@ -26,6 +27,31 @@ impl<T: Unique> Storage for UniqueStorage<T> {
     fn is_empty(&self) -> bool {
         false
     }
+    fn len(&self) -> Option<usize> {
+        Some(1)
+    }
+    fn clone_storage_to(
+        &self,
+        other_all_storages: &mut AllStorages,
+        storage_id: StorageId,
+    ) -> bool {
+        let Some(clone) = T::CLONE else {
+            return false;
+        };
+
+        other_all_storages.insert_cloned_storage(
+            storage_id,
+            UniqueStorage {
+                value: clone(&self.value),
+                insert: self.insert,
+                modification: self.modification,
+                last_insert: self.last_insert,
+                last_modification: self.last_modification,
+            },
+        );
+
+        true
+    }
 }
 
 impl<T: Unique> UniqueStorage<T> {
@@ -1,5 +1,6 @@
 mod all_storages;
 mod entities;
+mod entry;
 mod unique_or_default;
 mod unique_or_default_mut;
 mod unique_or_init;
@@ -11,6 +12,7 @@ mod view_mut;
 
 pub use all_storages::{AllStoragesView, AllStoragesViewMut};
 pub use entities::{EntitiesView, EntitiesViewMut};
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
 pub use unique_or_default::UniqueOrDefaultView;
 pub use unique_or_default_mut::UniqueOrDefaultViewMut;
 pub use unique_or_init::UniqueOrInitView;
@@ -0,0 +1,180 @@
+use crate::component::Component;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::r#mut::Mut;
+use crate::sparse_set::SparseSet;
+use crate::tracking::TrackingTimestamp;
+use crate::views::ViewMut;
+
+/// A single entity's component in a [`ViewMut`], obtained with [`ViewMut::entry`].
+pub enum Entry<'a, 'v, T: Component, Track> {
+    /// The entity has a component in this storage.
+    Occupied(OccupiedEntry<'a, 'v, T, Track>),
+    /// The entity doesn't have a component in this storage.
+    Vacant(VacantEntry<'a, 'v, T, Track>),
+}
+
+/// An [`Entry`] where the entity has a component.
+pub struct OccupiedEntry<'a, 'v, T: Component, Track> {
+    view: &'a mut ViewMut<'v, T, Track>,
+    entity: EntityId,
+    index: usize,
+}
+
+/// An [`Entry`] where the entity doesn't have a component.
+pub struct VacantEntry<'a, 'v, T: Component, Track> {
+    view: &'a mut ViewMut<'v, T, Track>,
+    entity: EntityId,
+}
+
+impl<'a, 'v, T: Component, Track> Entry<'a, 'v, T, Track> {
+    #[inline]
+    pub(crate) fn new(view: &'a mut ViewMut<'v, T, Track>, entity: EntityId) -> Self {
+        match view.sparse_set.index_of(entity) {
+            Some(index) => Entry::Occupied(OccupiedEntry {
+                view,
+                entity,
+                index,
+            }),
+            None => Entry::Vacant(VacantEntry { view, entity }),
+        }
+    }
+    /// Returns the entity of this entry.
+    #[inline]
+    pub fn entity(&self) -> EntityId {
+        match self {
+            Entry::Occupied(entry) => entry.entity,
+            Entry::Vacant(entry) => entry.entity,
+        }
+    }
+    /// Returns the component, inserting `component` if the entity doesn't have one.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive and doesn't have a component.
+    #[inline]
+    pub fn or_insert(
+        self,
+        entities: &Entities,
+        component: T,
+    ) -> Result<Mut<'a, T>, error::AddComponent> {
+        self.or_insert_with(entities, || component)
+    }
+    /// Returns the component, inserting the result of `f` if the entity doesn't have one.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive and doesn't have a component, `f` is then not called.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> T>(
+        self,
+        entities: &Entities,
+        f: F,
+    ) -> Result<Mut<'a, T>, error::AddComponent> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                if !entities.is_alive(entry.entity) {
+                    return Err(error::AddComponent::EntityIsNotAlive);
+                }
+
+                entry.insert(entities, f())
+            }
+        }
+    }
+    /// Calls `f` with the component if the entity has one.
+    #[inline]
+    pub fn and_modify<F: FnOnce(Mut<'_, T>)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, 'v, T: Component + Default, Track> Entry<'a, 'v, T, Track> {
+    /// Returns the component, inserting its `Default` value if the entity doesn't have one.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive and doesn't have a component.
+    #[inline]
+    pub fn or_default(self, entities: &Entities) -> Result<Mut<'a, T>, error::AddComponent> {
+        self.or_insert_with(entities, T::default)
+    }
+}
+
+impl<'a, 'v, T: Component, Track> OccupiedEntry<'a, 'v, T, Track> {
+    /// Returns a shared reference to the component.
+    #[inline]
+    pub fn get(&self) -> &T {
+        unsafe { self.view.sparse_set.data.get_unchecked(self.index) }
+    }
+    /// Returns an exclusive reference to the component.
+    #[inline]
+    pub fn get_mut(&mut self) -> Mut<'_, T> {
+        mut_at(self.view.sparse_set, self.index, self.view.current)
+    }
+    /// Returns an exclusive reference to the component bound to the view's lifetime.
+    #[inline]
+    pub fn into_mut(self) -> Mut<'a, T> {
+        let view = self.view;
+
+        mut_at(view.sparse_set, self.index, view.current)
+    }
+}
+
+impl<'a, 'v, T: Component, Track> VacantEntry<'a, 'v, T, Track> {
+    /// Inserts `component` and returns it.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    #[inline]
+    pub fn insert(
+        self,
+        entities: &Entities,
+        component: T,
+    ) -> Result<Mut<'a, T>, error::AddComponent> {
+        if !entities.is_alive(self.entity) {
+            return Err(error::AddComponent::EntityIsNotAlive);
+        }
+
+        let view = self.view;
+        let current = view.current;
+
+        view.sparse_set
+            .insert(self.entity, component, current)
+            .assert_inserted();
+
+        // At this point, it is not possible for the entity to not have a component of this type.
+        let index = unsafe { view.sparse_set.index_of_unchecked(self.entity) };
+
+        Ok(mut_at(view.sparse_set, index, current))
+    }
+}
+
+#[inline]
+fn mut_at<T: Component>(
+    sparse_set: &mut SparseSet<T>,
+    index: usize,
+    current: TrackingTimestamp,
+) -> Mut<'_, T> {
+    let SparseSet {
+        data,
+        modification_data,
+        is_tracking_modification,
+        ..
+    } = sparse_set;
+
+    Mut {
+        flag: is_tracking_modification
+            .then(|| unsafe { modification_data.get_unchecked_mut(index) }),
+        current,
+        data: unsafe { data.get_unchecked_mut(index) },
+        compare: T::MODIFICATION_COMPARE,
+        previous: None,
+    }
+}
@@ -4,7 +4,8 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
-use crate::sparse_set::SparseSet;
+use crate::iter::{IntoIter, Shiperator};
+use crate::sparse_set::{FullRawWindow, SparseSet};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
@@ -193,6 +194,37 @@ impl<'a, T: Component> View<'a, T, track::Untracked> {
             Err(error::CustomStorageView::WrongType(storage.name()))
         }
     }
+    /// Returns all components of this storage, index-aligned with [`entity_ids`](View::entity_ids).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{track, Component, View, World};
+    ///
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    /// impl Component for U32 {
+    ///     type Tracking = track::Untracked;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(U32(0));
+    /// let entity1 = world.add_entity(U32(1));
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// assert_eq!(u32s.dense(), [U32(0), U32(1)]);
+    /// assert_eq!(u32s.entity_ids(), [entity0, entity1]);
+    /// ```
+    #[inline]
+    pub fn dense(&self) -> &'a [T] {
+        &self.sparse_set.data
+    }
+    /// Returns the entities owning a component in this storage, index-aligned with [`dense`](View::dense).
+    #[inline]
+    pub fn entity_ids(&self) -> &'a [EntityId] {
+        &self.sparse_set.dense
+    }
 }
 
 impl<Track, T: Component> View<'_, T, Track>
@@ -247,6 +279,30 @@ where
     pub fn inserted_or_modified(&self) -> InsertedOrModified<&Self> {
         InsertedOrModified(self)
     }
+    /// Iterates *inserted* and *modified* components.\
+    /// Shorthand for `self.inserted_or_modified().iter()`, use [`with_id`](Shiperator::with_id) to get the entities.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntityId, World, View};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// #[track(Insertion, Modification)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid = world.add_entity(Health(10));
+    ///
+    /// let healths = world.borrow::<View<Health>>().unwrap();
+    ///
+    /// let changed: Vec<(EntityId, &Health)> = healths.iter_changed().with_id().collect();
+    /// assert_eq!(changed, vec![(eid, &Health(10))]);
+    /// ```
+    #[inline]
+    pub fn iter_changed(&self) -> Shiperator<InsertedOrModified<FullRawWindow<'_, T>>> {
+        self.inserted_or_modified().iter()
+    }
 
     /// Inside a workload returns `true` if `entity`'s component was inserted or modified since the last run of this system.\
     /// Outside workloads returns `true` if `entity`'s component was inserted or modified since the last call to [`clear_all_inserted`](crate::ViewMut::clear_all_inserted).\
@@ -268,6 +324,8 @@ where
     Track: DeletionTracking,
 {
     /// Returns the *deleted* components of a storage tracking deletion.
+    ///
+    /// See [`track::Removal`](crate::track::Removal) for how long they are kept.
     pub fn deleted(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
         self.sparse_set
             .deletion_data
@@ -295,6 +353,8 @@ where
     Track: RemovalTracking,
 {
     /// Returns the ids of *removed* components of a storage tracking removal.
+    ///
+    /// See [`track::Removal`](crate::track::Removal) for what is recorded and how long it is kept.
     pub fn removed(&self) -> impl Iterator<Item = EntityId> + '_ {
         self.sparse_set
             .removal_data
@@ -4,14 +4,16 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::error;
 use crate::get::Get;
+use crate::iter::{IntoIter, Shiperator};
 use crate::r#mut::Mut;
-use crate::sparse_set::{SparseSet, SparseSetDrain};
+use crate::sparse_set::{FullRawWindow, SparseSet, SparseSetDrain};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
     DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
     Modified, RemovalOrDeletionTracking, RemovalTracking, Tracking, TrackingTimestamp,
 };
+use crate::views::entry::Entry;
 use crate::views::view::View;
 use core::fmt;
 use core::marker::PhantomData;
@@ -182,6 +184,44 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
             Err(error::CustomStorageView::WrongType(name))
         }
     }
+    /// Returns an iterator over contiguous chunks of `chunk_size` components, in storage order.\
+    /// The last chunk can be shorter.
+    ///
+    /// Use [`id_at`](SparseSet::id_at) to get the entity owning the component at a given index.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{track, Component, ViewMut, World};
+    ///
+    /// struct Velocity(f32);
+    /// impl Component for Velocity {
+    ///     type Tracking = track::Untracked;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for i in 0..5 {
+    ///     world.add_entity(Velocity(i as f32));
+    /// }
+    ///
+    /// let mut velocities = world.borrow::<ViewMut<Velocity>>().unwrap();
+    ///
+    /// let lens = velocities
+    ///     .dense_chunks_mut(2)
+    ///     .map(|chunk| chunk.len())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lens, [2, 2, 1]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn dense_chunks_mut(&mut self, chunk_size: usize) -> core::slice::ChunksMut<'_, T> {
+        self.sparse_set.data.chunks_mut(chunk_size)
+    }
 }
 
 impl<'a, T: Component, Track> ViewMut<'a, T, Track>
@@ -192,7 +232,31 @@ where
     pub fn clear(&mut self) {
         self.sparse_set.private_clear(self.current);
     }
-    /// Creates a draining iterator that empties the storage and yields the removed items.
+    /// Creates a draining iterator that empties the storage and yields the removed items.\
+    /// Use [`SparseSetDrain::with_id`] to get the `EntityId` owning each component.
+    ///
+    /// The storage is emptied even if the iterator is dropped before the end.\
+    /// Drained components are tracked as removed, the entities stay alive.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Event(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Event(0));
+    /// let entity1 = world.add_entity(Event(1));
+    ///
+    /// let mut events = world.borrow::<ViewMut<Event>>().unwrap();
+    ///
+    /// let drained: Vec<_> = events.drain().with_id().collect();
+    ///
+    /// assert_eq!(drained, vec![(entity0, Event(0)), (entity1, Event(1))]);
+    /// assert!(events.is_empty());
+    /// ```
     pub fn drain(&mut self) -> SparseSetDrain<'_, T> {
         self.sparse_set.private_drain(self.current)
     }
@@ -224,6 +288,138 @@ where
         self.sparse_set.private_apply_mut(a, b, f, self.current)
     }
 
+    /// Applies the given function `f` to the entities `a`, `b` and `c`.\
+    /// The three entities shouldn't point to the same component.
+    ///
+    /// ### Errors
+    ///
+    /// - MissingComponent - if one of the entity doesn't have any component in the storage.
+    /// - IdenticalIds - if two entities point to the same component.
+    ///
+    /// Missing components are checked first, in `a`, `b`, `c` order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Mass(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let a = world.add_entity(Mass(1));
+    /// let b = world.add_entity(Mass(2));
+    /// let c = world.add_entity(Mass(3));
+    ///
+    /// let mut masses = world.borrow::<ViewMut<Mass>>().unwrap();
+    ///
+    /// let total = masses.apply3_mut(a, b, c, |a, b, c| a.0 + b.0 + c.0);
+    /// assert_eq!(total, Ok(6));
+    ///
+    /// assert_eq!(
+    ///     masses.apply3_mut(a, b, a, |_, _, _| {}),
+    ///     Err(error::Apply::IdenticalIds)
+    /// );
+    /// ```
+    pub fn apply3_mut<R, F: FnOnce(&mut T, &mut T, &mut T) -> R>(
+        &mut self,
+        a: EntityId,
+        b: EntityId,
+        c: EntityId,
+        f: F,
+    ) -> Result<R, error::Apply> {
+        self.sparse_set.private_apply3_mut(a, b, c, f, self.current)
+    }
+
+    /// Returns mutable references to the components of all `ids`.\
+    /// The entities shouldn't point to the same component.
+    ///
+    /// All components are flagged as modified.
+    ///
+    /// ### Errors
+    ///
+    /// - MissingComponent - if one of the entity doesn't have any component in the storage.
+    /// - IdenticalIds - if two entities point to the same component.
+    ///
+    /// Missing components are checked first, in `ids` order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Mass(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let a = world.add_entity(Mass(1));
+    /// let b = world.add_entity(Mass(2));
+    ///
+    /// let mut masses = world.borrow::<ViewMut<Mass>>().unwrap();
+    ///
+    /// let [a_mass, b_mass] = masses.get_disjoint_mut([a, b]).unwrap();
+    /// core::mem::swap(a_mass, b_mass);
+    /// assert_eq!(masses[a], Mass(2));
+    ///
+    /// assert_eq!(
+    ///     masses.get_disjoint_mut([a, a]).err(),
+    ///     Some(error::Apply::IdenticalIds)
+    /// );
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        ids: [EntityId; N],
+    ) -> Result<[&mut T; N], error::Apply> {
+        self.sparse_set.private_get_disjoint_mut(ids, self.current)
+    }
+
+    /// Applies the given function `f` to the components of all `ids`.\
+    /// The entities shouldn't point to the same component.
+    ///
+    /// ### Errors
+    ///
+    /// - MissingComponent - if one of the entity doesn't have any component in the storage.
+    /// - IdenticalIds - if two entities point to the same component.
+    ///
+    /// Missing components are checked first, in `ids` order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Mass(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let a = world.add_entity(Mass(1));
+    /// let b = world.add_entity(Mass(2));
+    /// let c = world.add_entity(Mass(3));
+    /// let d = world.add_entity(Mass(4));
+    ///
+    /// let mut masses = world.borrow::<ViewMut<Mass>>().unwrap();
+    ///
+    /// let total = masses.apply_many_mut([a, b, c, d], |masses| {
+    ///     masses.iter().map(|mass| mass.0).sum::<u32>()
+    /// });
+    /// assert_eq!(total, Ok(10));
+    ///
+    /// assert_eq!(
+    ///     masses.apply_many_mut([a, b, c, a], |_| {}),
+    ///     Err(error::Apply::IdenticalIds)
+    /// );
+    /// ```
+    pub fn apply_many_mut<R, const N: usize, F: FnOnce([&mut T; N]) -> R>(
+        &mut self,
+        ids: [EntityId; N],
+        f: F,
+    ) -> Result<R, error::Apply> {
+        self.get_disjoint_mut(ids).map(f)
+    }
+
     /// Deletes all components for which `f(id, &component)` returns `false`.
     pub fn retain<F: FnMut(EntityId, &T) -> bool>(&mut self, f: F) {
         self.sparse_set.private_retain(self.current, f);
@@ -233,6 +429,117 @@ where
     pub fn retain_mut<F: FnMut(EntityId, Mut<'_, T>) -> bool>(&mut self, f: F) {
         self.sparse_set.private_retain_mut(self.current, f);
     }
+
+    /// Applies `f` to `entity`'s component and removes it if `f` returns `false`.\
+    /// Returns the removed component.\
+    /// The component is only flagged modified if `f` mutably accessed it.
+    ///
+    /// Returns `None` if `f` returned `true` or if `entity` doesn't have a component in this storage, `f` is then not called.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Mut, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Cooldown(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Cooldown(2));
+    ///
+    /// let mut cooldowns = world.borrow::<ViewMut<Cooldown>>().unwrap();
+    ///
+    /// let tick = |mut cooldown: Mut<Cooldown>| {
+    ///     cooldown.0 -= 1;
+    ///     cooldown.0 > 0
+    /// };
+    ///
+    /// assert_eq!(cooldowns.update_or_remove(entity, tick), None);
+    /// assert_eq!(cooldowns.update_or_remove(entity, tick), Some(Cooldown(0)));
+    /// assert_eq!(cooldowns.update_or_remove(entity, tick), None);
+    /// ```
+    pub fn update_or_remove<F: FnOnce(Mut<'_, T>) -> bool>(
+        &mut self,
+        entity: EntityId,
+        f: F,
+    ) -> Option<T> {
+        self.sparse_set
+            .private_update_or_remove(entity, f, self.current)
+    }
+    /// Removes `entity`'s component and returns it along with the entity whose component was moved to fill its slot.\
+    /// The second element is `None` if the removed component was the last one or if `entity` didn't have a component.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(U32(0));
+    /// let entity1 = world.add_entity(U32(1));
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// assert_eq!(u32s.remove_reporting(entity0), (Some(U32(0)), Some(entity1)));
+    /// assert_eq!(u32s.remove_reporting(entity1), (Some(U32(1)), None));
+    /// ```
+    pub fn remove_reporting(&mut self, entity: EntityId) -> (Option<T>, Option<EntityId>) {
+        let Some(index) = self.sparse_set.index_of(entity) else {
+            return (None, None);
+        };
+
+        let component = self.sparse_set.dyn_remove(entity, self.current);
+
+        (component, self.sparse_set.dense.get(index).copied())
+    }
+    /// Returns `entity`'s component without checking it is present nor its generation.\
+    /// Modification tracking works like with [`Get`].
+    ///
+    /// # Safety
+    ///
+    /// `entity` has to own a component of this type.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    ///
+    /// if u32s.contains(entity) {
+    ///     unsafe { u32s.get_unchecked_mut(entity) }.0 += 1;
+    /// }
+    ///
+    /// assert_eq!(unsafe { u32s.get_unchecked(entity) }, &U32(1));
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, entity: EntityId) -> Mut<'_, T> {
+        let index = self.sparse_set.index_of_unchecked(entity);
+
+        let SparseSet {
+            data,
+            modification_data,
+            is_tracking_modification,
+            ..
+        } = &mut *self.sparse_set;
+
+        Mut {
+            flag: is_tracking_modification.then(|| modification_data.get_unchecked_mut(index)),
+            current: self.current,
+            data: data.get_unchecked_mut(index),
+            compare: T::MODIFICATION_COMPARE,
+            previous: None,
+        }
+    }
 }
 
 impl<'v, Track, T: Component + Default> ViewMut<'v, T, Track>
@@ -305,6 +612,38 @@ where
     }
 }
 
+impl<'v, Track, T: Component> ViewMut<'v, T, Track> {
+    /// Returns `entity`'s [`Entry`] in this storage, to inspect, modify or insert its component with a single lookup.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntitiesView, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Counter(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.add_entity(());
+    ///
+    /// let (entities, mut counters) = world
+    ///     .borrow::<(EntitiesView, ViewMut<Counter>)>()
+    ///     .unwrap();
+    ///
+    /// counters.entry(entity).or_insert(&entities, Counter(0)).unwrap();
+    /// counters
+    ///     .entry(entity)
+    ///     .and_modify(|mut counter| counter.0 += 1)
+    ///     .or_insert(&entities, Counter(0))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(counters[entity], Counter(1));
+    /// ```
+    #[inline]
+    pub fn entry<'a>(&'a mut self, entity: EntityId) -> Entry<'a, 'v, T, Track> {
+        Entry::new(self, entity)
+    }
+}
+
 impl<Track, T: Component> ViewMut<'_, T, Track>
 where
     Track: InsertionTracking,
@@ -331,6 +670,11 @@ where
     pub fn clear_all_inserted(self) {
         self.sparse_set.private_clear_all_inserted(self.current);
     }
+    /// Removes the *inserted* flag on `entity`'s component, other components keep theirs.
+    #[inline]
+    pub fn clear_inserted(&mut self, entity: EntityId) {
+        self.sparse_set.private_clear_inserted(entity);
+    }
 }
 
 impl<Track, T: Component> ViewMut<'_, T, Track>
@@ -364,6 +708,11 @@ where
     pub fn clear_all_modified(self) {
         self.sparse_set.private_clear_all_modified(self.current);
     }
+    /// Removes the *modified* flag on `entity`'s component, other components keep theirs.
+    #[inline]
+    pub fn clear_modified(&mut self, entity: EntityId) {
+        self.sparse_set.private_clear_modified(entity);
+    }
 }
 
 impl<Track, T: Component> ViewMut<'_, T, Track>
@@ -387,12 +736,63 @@ where
     pub fn inserted_or_modified_mut(&mut self) -> InsertedOrModified<&mut Self> {
         InsertedOrModified(self)
     }
+    /// Iterates *inserted* and *modified* components.\
+    /// Shorthand for `self.inserted_or_modified().iter()`, use [`with_id`](Shiperator::with_id) to get the entities.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntityId, World, ViewMut};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// #[track(Insertion, Modification)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let eid = world.add_entity(Health(10));
+    ///
+    /// let healths = world.borrow::<ViewMut<Health>>().unwrap();
+    ///
+    /// let changed: Vec<(EntityId, &Health)> = healths.iter_changed().with_id().collect();
+    /// assert_eq!(changed, vec![(eid, &Health(10))]);
+    /// ```
+    #[inline]
+    pub fn iter_changed(&self) -> Shiperator<InsertedOrModified<FullRawWindow<'_, T>>> {
+        self.inserted_or_modified().iter()
+    }
     /// Removes the *inserted* and *modified* flags on all components of this storage.
     #[inline]
     pub fn clear_all_inserted_and_modified(self) {
         self.sparse_set
             .private_clear_all_inserted_and_modified(self.current);
     }
+    /// Removes the *inserted* and *modified* flags on `entity`'s component, other components keep theirs.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion, Modification)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Health(10));
+    /// let entity1 = world.add_entity(Health(10));
+    ///
+    /// let mut healths = world.borrow::<ViewMut<Health>>().unwrap();
+    ///
+    /// healths.clear_inserted_and_modified(entity0);
+    ///
+    /// assert!(!healths.is_inserted_or_modified(entity0));
+    /// assert!(healths.is_inserted_or_modified(entity1));
+    /// ```
+    #[inline]
+    pub fn clear_inserted_and_modified(&mut self, entity: EntityId) {
+        self.sparse_set.private_clear_inserted(entity);
+        self.sparse_set.private_clear_modified(entity);
+    }
 }
 
 impl<Track, T: Component> ViewMut<'_, T, Track>
@@ -407,6 +807,8 @@ where
         Track::is_deleted(self, entity, self.last_removal_or_deletion, self.current)
     }
     /// Returns the *deleted* components of a storage tracking deletion.
+    ///
+    /// See [`track::Removal`](crate::track::Removal) for how long they are kept.
     pub fn deleted(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
         self.sparse_set
             .deletion_data
@@ -433,6 +835,8 @@ where
         Track::is_removed(self, entity, self.last_removal_or_deletion, self.current)
     }
     /// Returns the ids of *removed* components of a storage tracking removal.
+    ///
+    /// See [`track::Removal`](crate::track::Removal) for what is recorded and how long it is kept.
     pub fn removed(&self) -> impl Iterator<Item = EntityId> + '_ {
         self.sparse_set
             .removal_data
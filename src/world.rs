@@ -1,15 +1,25 @@
 mod builder;
+#[cfg(feature = "entity_tag")]
+mod entity_tag;
 mod run_batches;
+mod scoped;
 
 pub use builder::WorldBuilder;
+pub use scoped::ScopedWorld;
+
+#[cfg(feature = "entity_tag")]
+pub(crate) use entity_tag::EntityTag;
 
 use crate::all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetainStorage};
+#[cfg(feature = "alloc_stats")]
+use crate::alloc_stats::AllocStats;
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::WorldBorrow;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::error;
+use crate::events::Events;
 use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
 use crate::iter::{ShiperatorCaptain, ShiperatorSailor};
@@ -17,17 +27,21 @@ use crate::iter_component::{into_iter, IntoIterRef, IterComponent};
 use crate::memory_usage::WorldMemoryUsage;
 use crate::r#mut::Mut;
 use crate::reserve::BulkEntityIter;
-use crate::scheduler::info::WorkloadsInfo;
-use crate::scheduler::{AsLabel, Batches, Label, Scheduler};
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
+#[cfg(feature = "schedule_trace")]
+use crate::schedule_trace::{ScheduleTrace, ScheduledSystem};
+use crate::scheduler::info::{WorkloadInfo, WorkloadsInfo};
+use crate::scheduler::{AsLabel, Batches, Label, Scheduler, SystemHooks};
+use crate::sparse_set::{
+    BulkAddEntity, Snapshot, SparseSet, SpawnColumns, TupleAddComponent, TupleDelete, TupleRemove,
+};
 use crate::storage::{Storage, StorageId};
 use crate::system::System;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::views::EntitiesViewMut;
+use crate::views::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicU64;
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
@@ -36,6 +50,15 @@ pub struct World {
     counter: Arc<AtomicU64>,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
+    /// Position of the system that failed during the last workload run, `usize::MAX` if none did
+    last_failure: AtomicUsize,
+    /// Tag given to generated entities, see [`WorldBuilder::with_entity_tag`](crate::world::WorldBuilder::with_entity_tag)
+    #[cfg(feature = "entity_tag")]
+    entity_tag: Option<Arc<EntityTag>>,
+    #[cfg(feature = "alloc_stats")]
+    alloc_stats: std::sync::Mutex<alloc::vec::Vec<(Box<dyn Label>, AllocStats)>>,
+    #[cfg(feature = "schedule_trace")]
+    schedule_trace: ScheduleTrace,
 }
 
 #[cfg(feature = "std")]
@@ -55,6 +78,13 @@ impl Default for World {
             counter,
             #[cfg(feature = "parallel")]
             thread_pool: None,
+            last_failure: AtomicUsize::new(usize::MAX),
+            #[cfg(feature = "entity_tag")]
+            entity_tag: None,
+            #[cfg(feature = "alloc_stats")]
+            alloc_stats: std::sync::Mutex::new(alloc::vec::Vec::new()),
+            #[cfg(feature = "schedule_trace")]
+            schedule_trace: ScheduleTrace::new(),
         }
     }
 }
@@ -70,6 +100,20 @@ impl World {
     pub fn remove_local_thread_pool(&mut self) -> Option<rayon::ThreadPool> {
         self.thread_pool.take()
     }
+    /// Returns the number of threads workloads can use.\
+    /// This is the size of the local [`ThreadPool`](rayon::ThreadPool) if there is one, rayon's global pool otherwise.
+    #[cfg(feature = "parallel")]
+    pub fn thread_pool_size(&self) -> usize {
+        self.thread_pool.as_ref().map_or_else(
+            rayon::current_num_threads,
+            rayon::ThreadPool::current_num_threads,
+        )
+    }
+    /// Returns the tag given to the entities generated by this `World`, `0` if it isn't tagged.
+    #[cfg(feature = "entity_tag")]
+    pub(crate) fn entity_tag(&self) -> u8 {
+        self.entity_tag.as_ref().map_or(0, |tag| tag.get())
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a unique storage value, use [`UniqueView`] or [`UniqueViewMut`].
     ///
@@ -104,6 +148,115 @@ impl World {
     pub fn add_unique<T: Send + Sync + Unique>(&self, component: T) {
         self.all_storages.borrow().unwrap().add_unique(component);
     }
+    /// Returns an exclusive view over the unique storage `T`, adding it with the result of `f` if it doesn't exist yet.\
+    /// `f` is only called when the storage is missing.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `T` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Unique, World};
+    ///
+    /// #[derive(Unique, Default)]
+    /// struct Config(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.get_or_add_unique(Config::default).unwrap().0 = 5;
+    ///
+    /// assert_eq!(world.get_or_add_unique(|| Config(10)).unwrap().0, 5);
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    pub fn get_or_add_unique<T: Send + Sync + Unique, F: FnOnce() -> T>(
+        &self,
+        f: F,
+    ) -> Result<UniqueViewMut<'_, T>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .add_unique_with(f);
+
+        self.borrow::<UniqueViewMut<'_, T>>()
+    }
+    /// Registers an events channel for `E`, does nothing if it already exists.
+    ///
+    /// Events are sent with [`EventWriter`] and read with [`EventReader`].
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{EventReader, EventWriter, World};
+    ///
+    /// struct Hit(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// world.add_event::<Hit>();
+    ///
+    /// world.run(|mut hits: EventWriter<Hit>| hits.push(Hit(10)));
+    ///
+    /// world.run(|hits: EventReader<Hit>| {
+    ///     assert_eq!(hits.read().map(|hit| hit.0).sum::<u32>(), 10);
+    /// });
+    ///
+    /// world.update_events::<Hit>().unwrap();
+    /// world.update_events::<Hit>().unwrap();
+    ///
+    /// world.run(|hits: EventReader<Hit>| assert!(hits.is_empty()));
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`EventWriter`]: crate::EventWriter
+    /// [`EventReader`]: crate::EventReader
+    #[track_caller]
+    pub fn add_event<E: Send + Sync + 'static>(&self) {
+        let all_storages = self.all_storages.borrow().unwrap();
+
+        if let Err(error::GetStorage::MissingStorage { .. }) =
+            all_storages.borrow::<UniqueView<'_, Events<E>>>()
+        {
+            all_storages.add_unique(Events::<E>::default());
+        }
+    }
+    /// Swaps `E`'s events buffers, dropping the events sent before the previous update.\
+    /// This is usually called once per frame.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `E`'s events channel (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - The events channel was not registered with [`World::add_event`] or is already borrowed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    pub fn update_events<E: Send + Sync + 'static>(&self) -> Result<(), error::GetStorage> {
+        let mut events = self.borrow::<UniqueViewMut<'_, Events<E>>>()?;
+
+        events.update();
+
+        Ok(())
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a `!Send` unique storage value, use [`NonSend`] with [`UniqueView`] or [`UniqueViewMut`].
     /// Does nothing if the storage already exists.
@@ -277,7 +430,7 @@ You can use:
 * [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -376,6 +529,32 @@ let (entities, mut usizes) = world
 
         V::world_borrow(self, None, current)
     }
+    /// Returns `true` if `V` could be borrowed at the time of the call.
+    ///
+    /// The views are borrowed and immediately released, missing storages that [`World::borrow`] would create are created.\
+    /// This is best-effort: another thread can borrow a storage right after the check, a following [`World::borrow`] or [`World::run`] can still fail.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// assert!(world.can_borrow::<View<U32>>());
+    /// assert!(!world.can_borrow::<ViewMut<U32>>());
+    ///
+    /// drop(u32s);
+    ///
+    /// assert!(world.can_borrow::<ViewMut<U32>>());
+    /// ```
+    pub fn can_borrow<V: WorldBorrow>(&self) -> bool {
+        self.borrow::<V>().is_ok()
+    }
     #[doc = "Borrows the requested storages, runs the function and evaluates to the function's return value.
 Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.
 
@@ -387,7 +566,7 @@ You can use:
 * [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -508,7 +687,7 @@ You can use:
 * [AllStoragesViewMut] for an exclusive access to the storage of all components, ⚠️ can't coexist with any other storage borrow
 * [UniqueView]\\<T\\> for a shared access to a `T` unique storage
 * [UniqueViewMut]\\<T\\> for an exclusive access to a `T` unique storage
-* `Option<V>` with one or multiple views for fallible access to one or more storages"]
+* `Option<V>` with one or multiple views to access storages that might not exist, borrow errors are still returned"]
     #[cfg_attr(
         all(feature = "thread_local", docsrs),
         doc = "* <span style=\"display: table;color: #2f2f2f;background-color: #C4ECFF;border-width: 1px;border-style: solid;border-color: #7BA5DB;padding: 3px;margin-bottom: 5px; font-size: 90%\">This is supported on <strong><code style=\"background-color: #C4ECFF\">feature=\"thread_local\"</code></strong> only:</span>"
@@ -599,6 +778,8 @@ world
     });
 
 let i = world.run(sys1);
+
+let count: usize = world.run(|usizes: View<USIZE>| usizes.len());
 ```
 [AllStorages]: crate::AllStorages
 [EntitiesView]: crate::Entities
@@ -689,63 +870,71 @@ let i = world.run(sys1);
             &scheduler.system_names,
             batches,
             &*label,
+            0,
+            None,
         )
     }
-    /// Returns `true` if the world contains the `name` workload.
+    /// Runs the `label` workload, a custom error of type `E` returned by a system is given back as is.
+    ///
+    /// The outer `Result` holds scheduler, storage and other custom errors,
+    /// the inner one holds errors of type `E`.
     ///
     /// ### Borrows
     ///
     /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
     ///
-    /// ### Panics
+    /// ### Errors
     ///
     /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
     ///
     /// ### Example
     /// ```
     /// use shipyard::{Workload, World};
     ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct AssetNotLoaded;
+    ///
+    /// impl std::fmt::Display for AssetNotLoaded {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("asset not loaded")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for AssetNotLoaded {}
+    ///
+    /// fn load() -> Result<(), AssetNotLoaded> {
+    ///     Err(AssetNotLoaded)
+    /// }
+    ///
     /// let world = World::new();
     ///
-    /// Workload::new("foo").add_to_world(&world).unwrap();
+    /// Workload::new("load")
+    ///     .with_try_system(load)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
     ///
-    /// assert!(world.contains_workload("foo"));
-    /// assert!(!world.contains_workload("bar"));
+    /// let result = world.run_workload_typed::<AssetNotLoaded, _>("load").unwrap();
+    ///
+    /// assert_eq!(result, Err(AssetNotLoaded));
     /// ```
-    #[track_caller]
-    pub fn contains_workload<T>(&self, name: impl AsLabel<T>) -> bool {
-        let label = name.as_label();
-
-        self.scheduler.borrow().unwrap().contains_workload(&*label)
-    }
-    #[allow(clippy::type_complexity)]
-    pub(crate) fn run_batches(
+    #[cfg(feature = "std")]
+    pub fn run_workload_typed<E: std::error::Error + 'static, T>(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
-        system_names: &[Box<dyn Label>],
-        batches: &Batches,
-        workload_name: &dyn Label,
-    ) -> Result<(), error::RunWorkload> {
-        if let Some(run_if) = &batches.run_if {
-            if !run_if
-                .run(self)
-                .map_err(|err| error::RunWorkload::Run((workload_name.dyn_clone(), err)))?
-            {
-                return Ok(());
-            }
-        }
-
-        #[cfg(feature = "parallel")]
-        {
-            self.run_batches_parallel(systems, system_names, batches, workload_name)
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+        label: impl AsLabel<T>,
+    ) -> Result<Result<(), E>, error::RunWorkload> {
+        match self.run_workload(label) {
+            Ok(()) => Ok(Ok(())),
+            Err(err) => err.downcast_custom::<E>().map(Err),
         }
     }
-    /// Run the default workload if there is one.
+    /// Runs the `label` workload, a custom error of type `E` returned by a system is given back as is.
+    ///
+    /// The outer `Result` holds scheduler, storage and other custom errors,
+    /// the inner one holds errors of type `E`.
     ///
     /// ### Borrows
     ///
@@ -755,121 +944,565 @@ let i = world.run(sys1);
     /// ### Errors
     ///
     /// - Scheduler borrow failed.
+    /// - Workload did not exist.
     /// - Storage borrow failed.
     /// - User error returned by system.
-    pub fn run_default_workload(&self) -> Result<(), error::RunWorkload> {
-        let scheduler = self
-            .scheduler
-            .borrow()
-            .map_err(|_| error::RunWorkload::Scheduler)?;
-
-        if !scheduler.is_empty() {
-            self.run_batches(
-                &scheduler.systems,
-                &scheduler.system_names,
-                scheduler.default_workload(),
-                &scheduler.default,
-            )?
+    #[cfg(not(feature = "std"))]
+    pub fn run_workload_typed<E: 'static, T>(
+        &self,
+        label: impl AsLabel<T>,
+    ) -> Result<Result<(), E>, error::RunWorkload> {
+        match self.run_workload(label) {
+            Ok(()) => Ok(Ok(())),
+            Err(err) => err.downcast_custom::<E>().map(Err),
         }
-        Ok(())
     }
-    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// Runs the `label` workload, calling `hooks` around each system.
     ///
-    /// ### Errors
+    /// ### Borrows
     ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
-        self.all_storages.borrow()
-    }
-    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
     ///
     /// ### Errors
     ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
-        self.all_storages.borrow_mut()
-    }
-    /// Inserts a custom storage to the `World`.
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
     ///
-    /// ### Errors
+    /// ### Example
+    /// ```
+    /// use core::time::Duration;
+    /// use shipyard::{scheduler::Label, SystemHooks, Workload, World};
+    /// use std::sync::Mutex;
     ///
-    /// - `AllStorages` is already borrowed exclusively.
-    pub fn add_custom_storage<S: 'static + Storage + Send + Sync>(
+    /// #[derive(Default)]
+    /// struct Profiler(Mutex<Vec<(String, Duration)>>);
+    ///
+    /// impl SystemHooks for Profiler {
+    ///     fn on_system_end(&self, system: &dyn Label, duration: Duration) {
+    ///         self.0.lock().unwrap().push((format!("{:?}", system), duration));
+    ///     }
+    /// }
+    ///
+    /// fn physics() {}
+    /// fn render() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(physics)
+    ///     .with_system(render)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let profiler = Profiler::default();
+    /// world.run_workload_with_hooks("Frame", &profiler).unwrap();
+    ///
+    /// assert_eq!(profiler.0.lock().unwrap().len(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn run_workload_with_hooks<T>(
         &self,
-        storage_id: StorageId,
-        storage: S,
-    ) -> Result<(), error::Borrow> {
-        let _ = self
-            .all_storages
-            .borrow()?
-            .custom_storage_or_insert_by_id(storage_id, || storage);
+        label: impl AsLabel<T>,
+        hooks: &dyn SystemHooks,
+    ) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
 
-        Ok(())
-    }
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
 
-    /// Increments the current tracking cycle and returns the previous value.
-    #[inline]
-    pub(crate) fn get_current(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(
-            self.counter
-                .fetch_add(1, core::sync::atomic::Ordering::Acquire),
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            0,
+            Some(hooks),
         )
     }
-
-    /// Returns a timestamp used to clear tracking information.
-    pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(self.counter.load(core::sync::atomic::Ordering::Acquire))
-    }
-}
-
-impl World {
-    /// Creates a new entity with the components passed as argument and returns its `EntityId`.
-    /// `component` must always be a tuple, even for a single component.
+    /// Runs the `label` workload starting from the system at position `from_system`.\
+    /// Positions follow the sequential order of the workload, the position of the system that failed during the last run is returned by [`World::last_failed_system`].
     ///
-    /// ### Example
+    /// Batches are kept as they were built, the batch containing `from_system` runs without the systems positioned before it and all following batches run normally.
+    /// The workload's `run_if` and the systems' `run_if` are evaluated again, systems skipped by the resumption don't have their `run_if` called.
     ///
-    /// ```
-    /// use shipyard::{Component, World};
+    /// The [`World`] only keeps a single failed position, not one per workload.
+    /// Running any workload, including from another thread, replaces it, the position has to be read before running another workload.
     ///
-    /// #[derive(Component)]
-    /// struct U32(u32);
+    /// ### Borrows
     ///
-    /// #[derive(Component)]
-    /// struct USIZE(usize);
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
     ///
-    /// let mut world = World::new();
+    /// ### Errors
     ///
-    /// let entity0 = world.add_entity((U32(0),));
-    /// let entity1 = world.add_entity((U32(1), USIZE(11)));
-    /// ```
-    #[inline]
-    pub fn add_entity<C: TupleAddComponent>(&mut self, component: C) -> EntityId {
-        self.all_storages.get_mut().add_entity(component)
-    }
-    /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.
-    /// `source` must always yield a tuple, even for a single component.
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
     ///
     /// ### Example
-    ///
     /// ```
-    /// use shipyard::{Component, World};
+    /// use shipyard::{error, Unique, UniqueView, UniqueViewMut, Workload, World};
     ///
-    /// #[derive(Component)]
-    /// struct U32(u32);
+    /// #[derive(Unique)]
+    /// struct Ready(bool);
     ///
-    /// #[derive(Component)]
-    /// struct USIZE(usize);
+    /// fn first() {}
     ///
-    /// let mut world = World::new();
+    /// fn fallible(ready: UniqueView<Ready>) -> Result<(), error::Run> {
+    ///     if ready.0 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(error::Run::from_custom("not ready"))
+    ///     }
+    /// }
     ///
-    /// let new_entities = world.bulk_add_entity((10..20).map(|i| (U32(i as u32), USIZE(i))));
-    /// ```
-    #[inline]
+    /// let world = World::new();
+    /// world.add_unique(Ready(false));
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(first)
+    ///     .with_try_system(fallible)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(world.run_workload("Update").is_err());
+    /// let failed = world.last_failed_system().unwrap();
+    /// assert_eq!(failed, 1);
+    ///
+    /// world.run(|mut ready: UniqueViewMut<Ready>| ready.0 = true);
+    ///
+    /// world.resume_workload("Update", failed).unwrap();
+    /// assert_eq!(world.last_failed_system(), None);
+    /// ```
+    pub fn resume_workload<T>(
+        &self,
+        label: impl AsLabel<T>,
+        from_system: usize,
+    ) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            batches,
+            &*label,
+            from_system,
+            None,
+        )
+    }
+    /// Returns the position of the system that failed during the last workload run.\
+    /// When multiple systems of a parallel batch fail, the smallest position is returned.
+    ///
+    /// The position can be given to [`World::resume_workload`].\
+    /// It is shared by all workloads, the next workload run replaces it.
+    pub fn last_failed_system(&self) -> Option<usize> {
+        let position = self.last_failure.load(Ordering::Relaxed);
+
+        if position == usize::MAX {
+            None
+        } else {
+            Some(position)
+        }
+    }
+    /// Returns `true` if the world contains the `name` workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("foo").add_to_world(&world).unwrap();
+    ///
+    /// assert!(world.contains_workload("foo"));
+    /// assert!(!world.contains_workload("bar"));
+    /// ```
+    #[track_caller]
+    pub fn contains_workload<T>(&self, name: impl AsLabel<T>) -> bool {
+        let label = name.as_label();
+
+        self.scheduler.borrow().unwrap().contains_workload(&*label)
+    }
+    /// Returns the labels of the `name` workload's systems in execution order.\
+    /// Returns `None` if the workload does not exist.
+    ///
+    /// The labels compare equal to the label of the system they come from,
+    /// two versions of a workload can be compared to find added, removed or reordered systems.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::scheduler::AsLabel;
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("old")
+    ///     .with_system(sys1)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    /// Workload::new("new")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let old = world.workload_system_labels("old").unwrap();
+    /// let new = world.workload_system_labels("new").unwrap();
+    ///
+    /// assert_eq!(old, [sys1.as_label()]);
+    /// assert_ne!(old, new);
+    ///
+    /// let added: Vec<_> = new.iter().filter(|label| !old.contains(label)).collect();
+    /// assert_eq!(added, [&sys2.as_label()]);
+    /// ```
+    #[track_caller]
+    pub fn workload_system_labels<T>(
+        &self,
+        name: impl AsLabel<T>,
+    ) -> Option<alloc::vec::Vec<Box<dyn Label>>> {
+        let label = name.as_label();
+
+        self.scheduler.borrow().unwrap().system_labels(&*label)
+    }
+    /// Returns the allocations made by each system during the last workload run, in execution order.\
+    /// A [`CountingAllocator`](crate::alloc_stats::CountingAllocator) has to be installed as global allocator, otherwise all stats are zero.
+    ///
+    /// Allocations are attributed per thread, a system is only charged for what it allocates on the thread running it.\
+    /// In parallel batches each system runs on a single thread so the attribution stays exact,
+    /// but allocations made by rayon jobs spawned from inside a system are counted on the threads executing them and are missing from the stats.\
+    /// Systems running in parallel can finish in any order.
+    ///
+    /// ### Panics
+    ///
+    /// - A system panicked while its stats were recorded.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn collect() {
+    ///     let _ = std::hint::black_box(vec![0u8; 64]);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("").with_system(collect).add_to_world(&world).unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// let stats = world.last_run_alloc_stats();
+    /// assert_eq!(stats.len(), 1);
+    /// ```
+    #[cfg(feature = "alloc_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc_stats")))]
+    #[track_caller]
+    pub fn last_run_alloc_stats(&self) -> alloc::vec::Vec<(Box<dyn Label>, AllocStats)> {
+        self.alloc_stats.lock().unwrap().clone()
+    }
+    /// Returns the systems that ran during the last workload run, sorted by start order.
+    ///
+    /// Each system records the rayon thread that ran it and the positions of its start and end events,
+    /// use [`ScheduledSystem::overlaps`] to know which systems actually ran in parallel.\
+    /// Systems skipped by their `run_if` are not recorded.
+    ///
+    /// ### Panics
+    ///
+    /// - A system panicked while its trace was recorded.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// fn read1(_: View<Position>) {}
+    /// fn read2(_: View<Position>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("").with_system(read1).with_system(read2).add_to_world(&world).unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// let trace = world.last_run_schedule_trace();
+    /// assert_eq!(trace.len(), 2);
+    /// ```
+    #[cfg(feature = "schedule_trace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "schedule_trace")))]
+    #[track_caller]
+    pub fn last_run_schedule_trace(&self) -> alloc::vec::Vec<ScheduledSystem> {
+        self.schedule_trace.systems()
+    }
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn run_batches(
+        &self,
+        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        system_names: &[Box<dyn Label>],
+        batches: &Batches,
+        workload_name: &dyn Label,
+        from_system: usize,
+        hooks: Option<&dyn SystemHooks>,
+    ) -> Result<(), error::RunWorkload> {
+        self.last_failure.store(usize::MAX, Ordering::Relaxed);
+        #[cfg(feature = "alloc_stats")]
+        self.alloc_stats.lock().unwrap().clear();
+        #[cfg(feature = "schedule_trace")]
+        self.schedule_trace.clear();
+
+        if let Some(run_if) = &batches.run_if {
+            if !run_if
+                .run(self)
+                .map_err(|err| error::RunWorkload::Run((workload_name.dyn_clone(), err)))?
+            {
+                return Ok(());
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        let result = self.run_batches_parallel(
+            systems,
+            system_names,
+            batches,
+            workload_name,
+            from_system,
+            hooks,
+        );
+
+        #[cfg(not(feature = "parallel"))]
+        let result = self.run_batches_sequential(
+            systems,
+            system_names,
+            batches,
+            workload_name,
+            from_system,
+            hooks,
+        );
+
+        // Commands are applied even if a system failed, the systems that ran queued them.
+        // They stay queued if `AllStorages` is borrowed, they'll be applied at the next workload's end.
+        // `AllStorages` is only borrowed exclusively when there is something to apply.
+        #[cfg(feature = "std")]
+        if matches!(self.all_storages.borrow(), Ok(all_storages) if all_storages.has_pending_commands())
+        {
+            if let Ok(mut all_storages) = self.all_storages.borrow_mut() {
+                all_storages.apply_commands();
+            }
+        }
+
+        result
+    }
+    /// Run the default workload if there is one.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    pub fn run_default_workload(&self) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        if !scheduler.is_empty() {
+            self.run_batches(
+                &scheduler.systems,
+                &scheduler.system_names,
+                scheduler.default_workload(),
+                &scheduler.default,
+                0,
+                None,
+            )?
+        }
+        Ok(())
+    }
+    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
+        self.all_storages.borrow()
+    }
+    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
+        self.all_storages.borrow_mut()
+    }
+    /// Inserts a custom storage to the `World`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed exclusively.
+    pub fn add_custom_storage<S: 'static + Storage + Send + Sync>(
+        &self,
+        storage_id: StorageId,
+        storage: S,
+    ) -> Result<(), error::Borrow> {
+        let _ = self
+            .all_storages
+            .borrow()?
+            .custom_storage_or_insert_by_id(storage_id, || storage);
+
+        Ok(())
+    }
+
+    /// Increments the current tracking cycle and returns the previous value.
+    #[inline]
+    pub(crate) fn get_current(&self) -> TrackingTimestamp {
+        TrackingTimestamp::new(self.counter.fetch_add(1, Ordering::Acquire))
+    }
+
+    /// Returns a timestamp used to clear tracking information.
+    ///
+    /// The tracking cycle doesn't advance once per `run` or workload.\
+    /// It advances every time a timestamp is handed out: for each system run, even inside the same workload,
+    /// each [`World::borrow`] and each `World` or `AllStorages` method inserting, modifying or deleting components.\
+    /// Two systems of the same workload therefore see different cycles.
+    pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
+        TrackingTimestamp::new(self.counter.load(Ordering::Acquire))
+    }
+
+    /// Sets the current tracking cycle.\
+    /// Can be used to restore the tracking cycle of a saved [`World`] so that ticks remain consistent across sessions.
+    ///
+    /// Setting a cycle older than tracking information already present in the [`World`] will make it appear in the future.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{tracking::TrackingTimestamp, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.set_tracking_timestamp(TrackingTimestamp::new(1000));
+    /// assert_eq!(world.get_tracking_timestamp().get(), 1000);
+    /// ```
+    pub fn set_tracking_timestamp(&mut self, timestamp: TrackingTimestamp) {
+        self.counter.store(timestamp.get(), Ordering::Release);
+    }
+}
+
+impl World {
+    /// Creates a new entity with the components passed as argument and returns its `EntityId`.
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity((U32(0),));
+    /// let entity1 = world.add_entity((U32(1), USIZE(11)));
+    /// ```
+    #[inline]
+    pub fn add_entity<C: TupleAddComponent>(&mut self, component: C) -> EntityId {
+        self.all_storages.get_mut().add_entity(component)
+    }
+    /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.
+    /// `source` must always yield a tuple, even for a single component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let new_entities = world.bulk_add_entity((10..20).map(|i| (U32(i as u32), USIZE(i))));
+    /// ```
+    #[inline]
     pub fn bulk_add_entity<T: BulkAddEntity>(&mut self, source: T) -> BulkEntityIter<'_> {
         self.all_storages.get_mut().bulk_add_entity(source)
     }
+    /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.\
+    /// `columns` is a tuple of iterators, each one yielding the components of a single storage.\
+    /// All columns have to yield the same number of components and a different component type, otherwise no entity is created and an error is returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(u32, u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Tile(u8);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let positions = (0..4).map(|i| Position(i % 2, i / 2));
+    /// let tiles = [Tile(0), Tile(1), Tile(1), Tile(0)];
+    ///
+    /// let new_entities = world.spawn_columns((positions, tiles)).unwrap();
+    /// assert_eq!(new_entities.len(), 4);
+    ///
+    /// assert!(world.spawn_columns(([Tile(0)], Vec::<Position>::new())).is_err());
+    /// ```
+    #[inline]
+    pub fn spawn_columns<T: SpawnColumns>(
+        &mut self,
+        columns: T,
+    ) -> Result<BulkEntityIter<'_>, error::SpawnColumns> {
+        self.all_storages.get_mut().spawn_columns(columns)
+    }
     /// Adds components to an existing entity.
     /// If the entity already owned a component it will be replaced.
     /// `component` must always be a tuple, even for a single component.
@@ -927,6 +1560,32 @@ impl World {
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
         self.all_storages.get_mut().delete_component::<C>(entity)
     }
+    /// Deletes `entity`'s component from the storage identified by `storage_id`.\
+    /// Does nothing if the storage doesn't exist or `entity` doesn't have a component in it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, sparse_set::SparseSet, StorageId, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// world.delete_component_by_id(entity, StorageId::of::<SparseSet<U32>>());
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert!(!u32s.contains(entity));
+    /// });
+    /// ```
+    pub fn delete_component_by_id(&mut self, entity: EntityId, storage_id: StorageId) {
+        self.all_storages
+            .get_mut()
+            .delete_component_by_id(entity, storage_id);
+    }
     /// Removes components from an entity.
     /// `C` must always be a tuple, even for a single component.
     ///
@@ -975,7 +1634,56 @@ impl World {
     pub fn delete_entity(&mut self, entity: EntityId) -> bool {
         self.all_storages.get_mut().delete_entity(entity)
     }
-    /// Deletes all components of an entity without deleting the entity.
+    /// Creates a new entity with a copy of all `entity`'s components.\
+    /// Components are copied using [`Component::CLONE`].
+    ///
+    /// Nothing is copied if any component can't be cloned, the error lists their storages.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// #[shipyard(clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    /// let clone = world.clone_entity(entity).unwrap();
+    ///
+    /// assert_eq!(*world.get::<&U32>(clone).unwrap(), U32(0));
+    /// ```
+    pub fn clone_entity(&mut self, entity: EntityId) -> Result<EntityId, error::CloneEntity> {
+        self.all_storages.get_mut().clone_entity(entity)
+    }
+    /// Deletes all entities for which `f` returns `false` and all their components.\
+    /// Returns the number of entities deleted.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(i32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let alive = world.add_entity(Health(10));
+    /// let dead = world.add_entity(Health(0));
+    ///
+    /// assert_eq!(world.retain_entities(|entity| entity == alive), 1);
+    /// assert!(world.is_entity_alive(alive));
+    /// assert!(!world.is_entity_alive(dead));
+    /// ```
+    #[inline]
+    pub fn retain_entities<F: FnMut(EntityId) -> bool>(&mut self, f: F) -> usize {
+        self.all_storages.get_mut().retain_entities(f)
+    }
+    /// Removes all components of an entity without deleting the entity.\
+    /// Storages tracking removal record the components as removed.
     ///
     /// ### Example
     ///
@@ -1050,34 +1758,104 @@ impl World {
     ///
     /// let entity = world.add_entity((U32(0), USIZE(1)));
     ///
-    /// world.retain_storage::<SparseSet<U32>>(entity);
+    /// world.retain_storage::<SparseSet<U32>>(entity);
+    /// ```
+    #[inline]
+    pub fn retain_storage<S: TupleRetainStorage>(&mut self, entity: EntityId) {
+        self.all_storages.get_mut().retain_storage::<S>(entity);
+    }
+    /// Same as `retain_storage` but uses `StorageId` and not generics.
+    /// You should only use this method if you use a custom storage with a runtime id.
+    #[inline]
+    pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
+        self.all_storages
+            .get_mut()
+            .retain_storage_by_id(entity, excluded_storage);
+    }
+    /// Deletes all entities and components in the `World`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.clear();
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.all_storages.get_mut().clear();
+    }
+    /// Sorts `A` and `B` storages once so the entities present in both come first, in the same order.\
+    /// Returns the number of entities present in both storages.
+    ///
+    /// This is a one-shot sort, not a maintained group.
+    /// Inserting, removing or deleting components can break the order, call this method again to restore it.\
+    /// Iteration doesn't know about this order, it still looks up the sparse arrays and only benefits from the memory locality.
+    /// See [`SparseSet::co_locate_with`] for more details.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `A` storage (exclusive)
+    /// - `B` storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - `A` or `B` storage borrow failed, this includes `A` and `B` being the same component.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(Position(0.0));
+    /// world.add_entity((Position(1.0), Velocity(1.0)));
+    ///
+    /// assert_eq!(world.co_locate::<Position, Velocity>(), Ok(1));
     /// ```
-    #[inline]
-    pub fn retain_storage<S: TupleRetainStorage>(&mut self, entity: EntityId) {
-        self.all_storages.get_mut().retain_storage::<S>(entity);
-    }
-    /// Same as `retain_storage` but uses `StorageId` and not generics.
-    /// You should only use this method if you use a custom storage with a runtime id.
-    #[inline]
-    pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
-        self.all_storages
-            .get_mut()
-            .retain_storage_by_id(entity, excluded_storage);
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    pub fn co_locate<A: Send + Sync + Component, B: Send + Sync + Component>(
+        &self,
+    ) -> Result<usize, error::GetStorage> {
+        let (mut a, mut b) = self.borrow::<(ViewMut<'_, A>, ViewMut<'_, B>)>()?;
+
+        Ok(a.co_locate_with(&mut b))
     }
-    /// Deletes all entities and components in the `World`.
+    /// Deletes all entities and components, storages stay registered and uniques are kept.
+    ///
+    /// When `reset_ids` is `true`, the next entities get the same `EntityId`s as in a new `World`,
+    /// starting at index `0` with generation `0`.\
+    /// Deletion and removal tracking data is also cleared since it would refer to reused `EntityId`s.
     ///
     /// ### Example
     ///
     /// ```
-    /// use shipyard::World;
+    /// use shipyard::{Component, EntityId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Level(u32);
     ///
     /// let mut world = World::new();
     ///
-    /// world.clear();
+    /// world.add_entity(Level(0));
+    /// world.clear_entities(true);
+    ///
+    /// assert_eq!(world.add_entity(Level(1)), EntityId::new_from_index_and_gen(0, 0));
     /// ```
     #[inline]
-    pub fn clear(&mut self) {
-        self.all_storages.get_mut().clear();
+    pub fn clear_entities(&mut self, reset_ids: bool) {
+        self.all_storages.get_mut().clear_entities(reset_ids);
     }
     /// Clear all deletion and removal tracking data.
     pub fn clear_all_removed_and_deleted(&mut self) {
@@ -1095,6 +1873,29 @@ impl World {
     /// Make the given entity alive.
     /// Does nothing if an entity with a greater generation is already at this index.
     /// Returns `true` if the entity is successfully spawned.
+    ///
+    /// A spawned entity doesn't have any component, they can be added later with [`World::add_component`].\
+    /// This can be used to mirror the ids allocated by another [`World`], for example in lockstep networking.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Position(i32, i32);
+    ///
+    /// let mut server = World::new();
+    /// let mut client = World::new();
+    ///
+    /// // reserve an id without any component
+    /// let entity = server.add_entity(());
+    ///
+    /// assert!(client.spawn(entity));
+    /// assert!(client.is_entity_alive(entity));
+    ///
+    /// server.add_component(entity, Position(0, 0));
+    /// client.add_component(entity, Position(0, 0));
+    /// ```
     #[inline]
     pub fn spawn(&mut self, entity: EntityId) -> bool {
         self.all_storages.get_mut().spawn(entity)
@@ -1121,10 +1922,51 @@ impl World {
         self.all_storages.get_mut().retain_mut(f);
     }
 
+    /// Copies `T` storage, the copy can be read from other threads while the world keeps running.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `T` storage (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq)]
+    /// struct Pos(f32, f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Pos(0.0, 0.0));
+    ///
+    /// let snapshot = world.snapshot::<Pos>().unwrap();
+    ///
+    /// std::thread::spawn(move || {
+    ///     assert_eq!(snapshot.get(entity), Some(&Pos(0.0, 0.0)));
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// ```
+    pub fn snapshot<T: Component + Clone + Send + Sync>(
+        &self,
+    ) -> Result<Snapshot<T>, error::GetStorage> {
+        Ok(self.borrow::<View<'_, T>>()?.snapshot())
+    }
     /// Displays storages memory information.
     pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
         WorldMemoryUsage(self)
     }
+    /// Releases unused memory in all storages, returns the number of bytes freed.\
+    /// See [`SparseSet::shrink_to_fit`] for details.
+    pub fn shrink_to_fit(&mut self) -> usize {
+        self.all_storages.get_mut().shrink_to_fit()
+    }
     /// Returns a list of workloads and all information related to them.
     ///
     /// ### Borrows
@@ -1147,6 +1989,49 @@ impl World {
         )
     }
 
+    /// Returns a [DOT](https://graphviz.org/doc/info/lang.html) graph of the workload's batches, see [`WorkloadInfo::to_dot`].\
+    /// Returns `None` if there is no workload with this name.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// fn read(_: View<Position>) {}
+    /// fn write(_: ViewMut<Position>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Move")
+    ///     .with_system(read)
+    ///     .with_system(write)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let dot = world.workload_to_dot("Move").unwrap();
+    /// assert!(dot.contains("cluster_1"));
+    /// ```
+    #[track_caller]
+    pub fn workload_to_dot<T>(&self, name: impl AsLabel<T>) -> Option<alloc::string::String> {
+        let label = name.as_label();
+        let scheduler = self.scheduler.borrow().unwrap();
+
+        scheduler
+            .workloads_info
+            .get(&label)
+            .map(WorkloadInfo::to_dot)
+    }
+
     /// Enable insertion tracking for the given components.
     pub fn track_insertion<T: TupleTrack>(&mut self) -> &mut World {
         self.all_storages.get_mut().track_insertion::<T>();
@@ -1176,6 +2061,208 @@ impl World {
         self.all_storages.get_mut().track_all::<T>();
     }
 
+    /// Creates the storage of `T` if it doesn't exist yet.\
+    /// Returns `true` if the storage was created.
+    ///
+    /// Registering storages upfront avoids creating them the first time a component is added.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// assert!(world.register::<U32>());
+    /// assert!(!world.register::<U32>());
+    /// ```
+    pub fn register<T: Send + Sync + Component>(&mut self) -> bool {
+        self.all_storages.get_mut().register::<T>()
+    }
+
+    /// Creates the storage of `T` if it doesn't exist yet and reserves memory for at least `capacity` components.\
+    /// Returns `true` if the storage was created.
+    ///
+    /// More memory can be reserved later with [`SparseSet::reserve`](crate::SparseSet::reserve) through a [`ViewMut`](crate::ViewMut).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// assert!(world.register_with_capacity::<U32>(100));
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert!(u32s.is_empty());
+    /// });
+    /// ```
+    pub fn register_with_capacity<T: Send + Sync + Component>(&mut self, capacity: usize) -> bool {
+        self.all_storages
+            .get_mut()
+            .register_with_capacity::<T>(capacity)
+    }
+
+    /// Creates the storage of `T` if it doesn't exist yet and enables all tracking for it.\
+    /// Returns `true` if the storage was created.
+    pub fn register_tracked<T: Send + Sync + Component>(&mut self) -> bool {
+        self.all_storages.get_mut().register_tracked::<T>()
+    }
+
+    /// Sets the callback called when a `T` component is inserted.\
+    /// Creates the storage if it doesn't exist yet.
+    ///
+    /// The callback is called synchronously when the component is added, while the storage is borrowed.\
+    /// It isn't called for components added with [`World::bulk_add_entity`] or [`World::spawn_columns`].\
+    /// It replaces any callback previously set with [`SparseSet::on_insertion`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let count = Arc::new(AtomicUsize::new(0));
+    /// let counter = count.clone();
+    /// world.on_insertion::<Position>(move |_, _| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// world.add_entity(Position(0.0, 0.0));
+    ///
+    /// assert_eq!(count.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn on_insertion<T: Send + Sync + Component>(
+        &mut self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) {
+        self.all_storages.get_mut().on_insertion::<T>(f);
+    }
+
+    /// Sets the callback called when a `T` component is removed or deleted, including when its entity is deleted.\
+    /// Creates the storage if it doesn't exist yet.
+    ///
+    /// The callback is called synchronously with the component being removed, while the storage is borrowed.\
+    /// It replaces any callback previously set with [`SparseSet::on_removal`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntityId, World};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let removed = Arc::new(Mutex::new(Vec::new()));
+    /// let grid = removed.clone();
+    /// world.on_removal::<Position>(move |entity, _| {
+    ///     grid.lock().unwrap().push(entity);
+    /// });
+    ///
+    /// let entity = world.add_entity(Position(0.0, 0.0));
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(*removed.lock().unwrap(), [entity]);
+    /// ```
+    pub fn on_removal<T: Send + Sync + Component>(
+        &mut self,
+        f: impl FnMut(EntityId, &T) + Send + Sync + 'static,
+    ) {
+        self.all_storages.get_mut().on_removal::<T>(f);
+    }
+
+    /// Sets a callback called with the id and name of each storage created after this call.\
+    /// It is called once per storage, whether the storage is created by a borrow or by adding a component, and isn't called for [`Unique`] storages.
+    ///
+    /// The callback is called while the storages are locked, it must not access the `World`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let registered = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let counter = registered.clone();
+    /// world.on_storage_registered(move |_, name| {
+    ///     assert!(name.contains("U32"));
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// world.run(|_: View<U32>| {});
+    /// world.add_entity(U32(0));
+    ///
+    /// assert_eq!(registered.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn on_storage_registered(
+        &mut self,
+        f: impl Fn(StorageId, &'static str) + Send + Sync + 'static,
+    ) {
+        self.all_storages.get_mut().on_storage_registered(f);
+    }
+
+    /// Makes all subsequent exclusive borrows of `T`'s storage fail with [`Borrow::Frozen`] until [`World::unfreeze_storage`] is called.\
+    /// Shared borrows are still allowed.\
+    /// Methods taking `&mut World` or `&mut AllStorages` access storages directly and are not affected.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.freeze_storage::<U32>();
+    ///
+    /// assert!(world.borrow::<View<U32>>().is_ok());
+    /// assert!(matches!(
+    ///     world.borrow::<ViewMut<U32>>().err(),
+    ///     Some(error::GetStorage::StorageBorrow {
+    ///         borrow: error::Borrow::Frozen,
+    ///         ..
+    ///     })
+    /// ));
+    ///
+    /// world.unfreeze_storage::<U32>();
+    ///
+    /// assert!(world.borrow::<ViewMut<U32>>().is_ok());
+    /// ```
+    ///
+    /// [`Borrow::Frozen`]: crate::error::Borrow::Frozen
+    pub fn freeze_storage<T: Component + Send + Sync>(&mut self) {
+        let all_storages = self.all_storages.get_mut();
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        all_storages.exclusive_storage_or_insert_mut(storage_id, SparseSet::<T>::new);
+        all_storages.set_frozen_by_id(storage_id, true);
+    }
+
+    /// Allows exclusive borrows of `T`'s storage again after a call to [`World::freeze_storage`].
+    pub fn unfreeze_storage<T: Component>(&mut self) {
+        self.all_storages
+            .get_mut()
+            .set_frozen_by_id(StorageId::of::<SparseSet<T>>(), false);
+    }
+
     #[doc = "Retrieve components of `entity`.
 
 Multiple components can be queried at the same time using a tuple.
@@ -1432,6 +2519,31 @@ for (i, j) in &mut iter {
             .is_alive(entity)
     }
 
+    /// Returns true if entity matches a living entity.\
+    /// Unlike [`World::is_entity_alive`], only requires a shared reference.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn is_alive(&self, entity: EntityId) -> bool {
+        self.borrow::<EntitiesView<'_>>().unwrap().is_alive(entity)
+    }
+
+    /// Attaches a debug name to `entity`, replacing the previous one.\
+    /// See [`AllStorages::set_entity_name`] for details.
+    ///
+    /// [`AllStorages::set_entity_name`]: crate::AllStorages::set_entity_name
+    pub fn set_entity_name(&mut self, entity: EntityId, name: impl Into<alloc::string::String>) {
+        self.all_storages.get_mut().set_entity_name(entity, name);
+    }
+
     /// Moves an entity from a `World` to another.
     ///
     /// ```
@@ -1487,6 +2599,100 @@ for (i, j) in &mut iter {
             .get_mut()
             .move_components(other_all_storages, from, to);
     }
+
+    /// Moves `ids` and all their components to another `World`.\
+    /// See [`AllStorages::migrate_entities`] for details.
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut chunk = World::new();
+    /// let mut main = World::new();
+    ///
+    /// main.add_entity(USIZE(0));
+    /// let entity = chunk.add_entity(USIZE(1));
+    ///
+    /// let migrated = chunk.migrate_entities(&mut main, &[entity]);
+    /// let (_, new_entity) = migrated[0];
+    ///
+    /// assert!(!chunk.is_entity_alive(entity));
+    /// assert_eq!(main.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    ///
+    /// [`AllStorages::migrate_entities`]: crate::AllStorages::migrate_entities
+    #[inline]
+    pub fn migrate_entities(
+        &mut self,
+        other: &mut World,
+        ids: &[EntityId],
+    ) -> alloc::vec::Vec<(EntityId, EntityId)> {
+        let other_all_storages = other.all_storages.get_mut();
+
+        self.all_storages
+            .get_mut()
+            .migrate_entities(other_all_storages, ids)
+    }
+
+    /// Creates a new `World` with a copy of all entities, components and uniques.\
+    /// Entities keep the same `EntityId`, tracking information is copied too.
+    ///
+    /// Workloads, callbacks and the local thread pool are not copied.
+    ///
+    /// All components and uniques have to opt in by setting [`Component::CLONE`] or [`Unique::CLONE`].
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    /// - A storage doesn't support cloning.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    /// #[shipyard(clone)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// let mut snapshot = world.try_clone().unwrap();
+    ///
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(*snapshot.get::<&U32>(entity).unwrap(), &U32(0));
+    /// assert_ne!(snapshot.add_entity(()), entity);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_clone(&self) -> Result<World, error::CloneWorld> {
+        let all_storages = self
+            .all_storages
+            .borrow()
+            .map_err(error::CloneWorld::AllStoragesBorrow)?;
+
+        let mut world = World::new();
+        #[cfg(feature = "entity_tag")]
+        {
+            world.entity_tag = self.entity_tag.clone();
+        }
+        world
+            .counter
+            .store(self.counter.load(Ordering::Acquire), Ordering::Release);
+
+        all_storages.clone_storages_to(world.all_storages.get_mut())?;
+
+        Ok(world)
+    }
 }
 
 impl core::fmt::Debug for World {
@@ -1,9 +1,13 @@
 use crate::all_storages::{AllStoragesBuilder, LockPresent, ThreadIdPresent};
 use crate::atomic_refcell::AtomicRefCell;
+#[cfg(feature = "entity_tag")]
+use crate::error;
 use crate::public_transport::ShipyardRwLock;
+#[cfg(feature = "entity_tag")]
+use crate::world::EntityTag;
 use crate::world::World;
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicU64;
+use core::sync::atomic::{AtomicU64, AtomicUsize};
 
 /// Builder for [`World`] when one wants custom lock, custom thread pool
 /// or custom thread id provider function.
@@ -11,6 +15,8 @@ pub struct WorldBuilder<Lock, ThreadId> {
     all_storages_builder: AllStoragesBuilder<Lock, ThreadId>,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
+    #[cfg(feature = "entity_tag")]
+    entity_tag: Option<Arc<EntityTag>>,
 }
 
 impl World {
@@ -22,6 +28,8 @@ impl World {
             all_storages_builder: AllStoragesBuilder::<LockPresent, ThreadIdPresent>::new(),
             #[cfg(feature = "parallel")]
             thread_pool: None,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: None,
         }
     }
 
@@ -34,6 +42,8 @@ impl World {
                 crate::all_storages::MissingLock,
                 ThreadIdPresent,
             >::new(),
+            #[cfg(feature = "entity_tag")]
+            entity_tag: None,
         }
     }
 
@@ -47,6 +57,8 @@ impl World {
                 crate::all_storages::MissingLock,
                 crate::all_storages::MissingThreadId,
             >::new(),
+            #[cfg(feature = "entity_tag")]
+            entity_tag: None,
         }
     }
 }
@@ -62,6 +74,8 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
             all_storages_builder: self.all_storages_builder.with_custom_lock::<L>(),
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: self.entity_tag,
         }
     }
 
@@ -83,9 +97,75 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
             all_storages_builder: self.all_storages_builder.with_custom_thread_id(thread_id),
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
+            #[cfg(feature = "entity_tag")]
+            entity_tag: self.entity_tag,
         }
     }
 
+    /// Seed the hasher [`AllStorages`] uses to store its storages.\
+    /// Without seed [`AllStorages`] uses the default fast hasher, with `std` its keys are random.
+    /// A seeded [`World`] uses `SipHasher13` keyed with the seed instead, it's slower to find a storage.
+    ///
+    /// With the same seed and the same binary, [`World`]s enumerate their storages in the same order.
+    /// This order is used by [`World::clear`], [`World::delete_entity`], [`World::strip`], [`AllStorages::retain_entities`], [`World::memory_usage`], [`AllStorages::memory_report`] and `World`'s `Debug` output.\
+    /// Changing the seed will shuffle this order.
+    ///
+    /// Component iteration follows the dense array of each storage and doesn't depend on the seed.\
+    /// Storage ids of Rust types are derived from `TypeId`, the order can change between compilations.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::builder().with_seed(42).build();
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`AllStorages::retain_entities`]: crate::AllStorages::retain_entities
+    /// [`AllStorages::memory_report`]: crate::AllStorages::memory_report
+    pub fn with_seed(mut self, seed: u64) -> WorldBuilder<Lock, ThreadId> {
+        self.all_storages_builder = self.all_storages_builder.with_seed(seed);
+
+        self
+    }
+
+    /// Tags the entities generated by this [`World`] with a small identifier no other living [`World`] uses.\
+    /// At most 255 tags can be in use at the same time, a tag is freed once the [`World`] using it and all its clones are dropped.
+    ///
+    /// The tag is stored in the [`EntityId`] bits, it lets [`EntityId::belongs_to`] catch ids used with the wrong [`World`]
+    /// and the alternate `Debug` format (`{:#?}`) print it as `EId(w1:3.0)`.\
+    /// Untagged [`World`]s use the tag `0`, an [`EntityId`] built with [`EntityId::new_from_index_and_gen`] is never equal to a tagged one.
+    ///
+    /// Ids generated after a tag is freed can be mistaken for ids of the next [`World`] using it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let mut world = World::builder().with_entity_tag().unwrap().build();
+    ///
+    /// let entity = world.add_entity(());
+    ///
+    /// assert_ne!(entity.world_tag(), 0);
+    /// assert!(entity.belongs_to(&world));
+    /// assert!(!entity.belongs_to(&World::new()));
+    /// ```
+    ///
+    /// [`EntityId`]: crate::EntityId
+    /// [`EntityId::belongs_to`]: crate::EntityId::belongs_to
+    /// [`EntityId::new_from_index_and_gen`]: crate::EntityId::new_from_index_and_gen
+    #[cfg(feature = "entity_tag")]
+    pub fn with_entity_tag(mut self) -> Result<WorldBuilder<Lock, ThreadId>, error::EntityTag> {
+        let tag = EntityTag::acquire().ok_or(error::EntityTag)?;
+
+        self.all_storages_builder = self.all_storages_builder.with_entity_tag(tag.get());
+        self.entity_tag = Some(Arc::new(tag));
+
+        Ok(self)
+    }
+
     /// Use a local [`ThreadPool`](rayon::ThreadPool).
     ///
     /// This is useful when you have multiple [`Worlds`](World) or something else using [`rayon`] and want them to stay isolated.\
@@ -100,6 +180,36 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
 
         self
     }
+
+    /// Use a local [`ThreadPool`](rayon::ThreadPool) with the default number of threads, named `{prefix}-{index}`.
+    ///
+    /// Named threads are easier to tell apart in profilers and debuggers.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::builder()
+    ///     .with_thread_pool_name_prefix("physics")
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(world.thread_pool_size(), rayon::current_num_threads());
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn with_thread_pool_name_prefix(
+        self,
+        prefix: &str,
+    ) -> Result<WorldBuilder<Lock, ThreadId>, rayon::ThreadPoolBuildError> {
+        let prefix = alloc::string::String::from(prefix);
+
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(move |index| alloc::format!("{}-{}", prefix, index))
+            .build()?;
+
+        Ok(self.with_local_thread_pool(thread_pool))
+    }
 }
 
 impl WorldBuilder<LockPresent, ThreadIdPresent> {
@@ -115,6 +225,13 @@ impl WorldBuilder<LockPresent, ThreadIdPresent> {
             counter,
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
+            last_failure: AtomicUsize::new(usize::MAX),
+            #[cfg(feature = "entity_tag")]
+            entity_tag: self.entity_tag,
+            #[cfg(feature = "alloc_stats")]
+            alloc_stats: std::sync::Mutex::new(alloc::vec::Vec::new()),
+            #[cfg(feature = "schedule_trace")]
+            schedule_trace: crate::schedule_trace::ScheduleTrace::new(),
         }
     }
 }
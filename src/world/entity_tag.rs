@@ -0,0 +1,62 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Tags in use, bit `n` of word `n / 64` is tag `n`.\
+/// Tag `0` is used by untagged worlds and is never handed out.
+static TAGS_IN_USE: [AtomicU64; 4] = [
+    AtomicU64::new(1),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+];
+
+/// World tag reserved until the last [`World`](crate::World) using it is dropped.
+pub(crate) struct EntityTag(u8);
+
+impl EntityTag {
+    /// Reserves a tag no other living [`World`](crate::World) uses.\
+    /// Returns `None` if all 255 tags are in use.
+    pub(crate) fn acquire() -> Option<EntityTag> {
+        for (word_index, word) in TAGS_IN_USE.iter().enumerate() {
+            let mut current = word.load(Ordering::Relaxed);
+
+            while current != u64::MAX {
+                let bit = (!current).trailing_zeros();
+
+                match word.compare_exchange_weak(
+                    current,
+                    current | (1 << bit),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return Some(EntityTag((word_index * 64) as u8 + bit as u8)),
+                    Err(new) => current = new,
+                }
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Drop for EntityTag {
+    fn drop(&mut self) {
+        TAGS_IN_USE[self.0 as usize / 64].fetch_and(!(1 << (self.0 % 64)), Ordering::Release);
+    }
+}
+
+#[test]
+fn acquire_release() {
+    let tag = EntityTag::acquire().unwrap();
+    let value = tag.get();
+    assert_ne!(value, 0);
+
+    let other = EntityTag::acquire().unwrap();
+    assert_ne!(other.get(), value);
+
+    drop(tag);
+    drop(other);
+}
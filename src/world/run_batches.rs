@@ -1,7 +1,12 @@
+#[cfg(feature = "alloc_stats")]
+use crate::alloc_stats::AllocStats;
 use crate::error;
-use crate::scheduler::{Batches, Label};
+use crate::scheduler::{
+    Batches, ErrorHandler, ErrorResolution, Label, RepeatedBatches, SystemHooks,
+};
 use crate::world::World;
 use alloc::boxed::Box;
+use core::sync::atomic::Ordering;
 
 impl World {
     #[cfg(feature = "parallel")]
@@ -12,6 +17,8 @@ impl World {
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        from_system: usize,
+        hooks: Option<&dyn SystemHooks>,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
@@ -19,89 +26,183 @@ impl World {
         let _parent_span = parent_span.enter();
 
         let run_batch = || -> Result<(), error::RunWorkload> {
-            for (batch, batches_run_if) in batches.parallel.iter().zip(&batches.parallel_run_if) {
+            let mut conditions = alloc::vec![None; batches.conditions.len()];
+            let mut repeated = batches.repeated.iter().peekable();
+
+            for (i, (batch, batches_run_if)) in batches
+                .parallel
+                .iter()
+                .zip(&batches.parallel_run_if)
+                .enumerate()
+            {
+                while let Some(segment) = repeated.next_if(|segment| segment.parallel_position <= i)
+                {
+                    if segment.sequential_position >= from_system
+                        && self
+                            .check_conditions(batches, &segment.conditions, &mut conditions)
+                            .map_err(|err| error::RunWorkload::Run((segment.name.clone(), err)))?
+                    {
+                        self.run_repeated(systems, system_names, segment, hooks)?;
+                    }
+                }
+
                 let mut result = Ok(());
                 let run_if = (
                     if let Some(run_if_index) = batches_run_if.0 {
-                        if let Some(run_if) = &batches.sequential_run_if[run_if_index] {
-                            (run_if)(self).map_err(|err| {
-                                error::RunWorkload::Run((
-                                    system_names[batch.0.unwrap()].clone(),
-                                    err,
-                                ))
-                            })?
-                        } else {
-                            true
-                        }
+                        run_if_index >= from_system
+                            && self.should_run(
+                                batches,
+                                system_names,
+                                run_if_index,
+                                &mut conditions,
+                            )?
                     } else {
                         true
                     },
                     batches_run_if
                         .1
                         .iter()
-                        .map(|run_if_index| {
-                            if let Some(run_if) = &batches.sequential_run_if[*run_if_index] {
-                                (run_if)(self).map_err(|err| {
-                                    error::RunWorkload::Run((
-                                        system_names[batches.sequential[*run_if_index]].clone(),
-                                        err,
-                                    ))
-                                })
-                            } else {
-                                Ok(true)
-                            }
+                        .map(|&run_if_index| {
+                            Ok(run_if_index >= from_system
+                                && self.should_run(
+                                    batches,
+                                    system_names,
+                                    run_if_index,
+                                    &mut conditions,
+                                )?)
                         })
                         .collect::<Result<alloc::vec::Vec<_>, error::RunWorkload>>()?,
                 );
 
                 let mut start = 0;
-                let single_system = batch.0.filter(|_| run_if.0).or_else(|| {
-                    let system = batch.1.first().copied().filter(|_| run_if.1[0]);
+                let single_system =
+                    batch
+                        .0
+                        .zip(batches_run_if.0)
+                        .filter(|_| run_if.0)
+                        .or_else(|| {
+                            let system = batch
+                                .1
+                                .first()
+                                .copied()
+                                .zip(batches_run_if.1.first().copied())
+                                .filter(|_| run_if.1[0]);
+
+                            if system.is_some() {
+                                start = 1;
+                            }
+
+                            system
+                        });
+
+                let run_systems = |indices: &[usize],
+                                   positions: &[usize],
+                                   should_run: &[bool]|
+                 -> Result<(), error::RunWorkload> {
+                    use rayon::prelude::*;
 
-                    if system.is_some() {
-                        start = 1;
+                    indices
+                        .par_iter()
+                        .zip(positions)
+                        .zip(should_run)
+                        .try_for_each(|((&index, &position), should_run)| {
+                            if !should_run {
+                                return Ok(());
+                            }
+
+                            #[cfg(feature = "tracing")]
+                            {
+                                self.run_single_system(
+                                    systems,
+                                    system_names,
+                                    &parent_span,
+                                    index,
+                                    position,
+                                    hooks,
+                                    batches.on_error.as_ref(),
+                                )
+                            }
+                            #[cfg(not(feature = "tracing"))]
+                            {
+                                self.run_single_system(
+                                    systems,
+                                    system_names,
+                                    index,
+                                    position,
+                                    hooks,
+                                    batches.on_error.as_ref(),
+                                )
+                            }
+                        })
+                };
+
+                if let Some(max_threads) = batches.max_threads {
+                    if let Some((index, position)) = single_system {
+                        #[cfg(feature = "tracing")]
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            &parent_span,
+                            index,
+                            position,
+                            hooks,
+                            batches.on_error.as_ref(),
+                        )?;
+                        #[cfg(not(feature = "tracing"))]
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            index,
+                            position,
+                            hooks,
+                            batches.on_error.as_ref(),
+                        )?;
                     }
 
-                    system
-                });
+                    for ((indices, positions), should_run) in batch.1[start..]
+                        .chunks(max_threads)
+                        .zip(batches_run_if.1[start..].chunks(max_threads))
+                        .zip(run_if.1[start..].chunks(max_threads))
+                    {
+                        run_systems(indices, positions, should_run)?;
+                    }
+
+                    continue;
+                }
 
                 rayon::in_place_scope(|scope| {
                     // This check exists to avoid spawning a parallel job when possible.
                     // On wasm it causes a "condvar wait not supported" error.
                     if start < batch.1.len() {
                         scope.spawn(|_| {
-                            use rayon::prelude::*;
-
-                            result = batch.1[start..]
-                                .par_iter()
-                                .zip(&run_if.1[start..])
-                                .try_for_each(|(&index, should_run)| {
-                                    if !should_run {
-                                        return Ok(());
-                                    }
-
-                                    #[cfg(feature = "tracing")]
-                                    {
-                                        self.run_single_system(
-                                            systems,
-                                            system_names,
-                                            &parent_span,
-                                            index,
-                                        )
-                                    }
-                                    #[cfg(not(feature = "tracing"))]
-                                    {
-                                        self.run_single_system(systems, system_names, index)
-                                    }
-                                });
+                            result = run_systems(
+                                &batch.1[start..],
+                                &batches_run_if.1[start..],
+                                &run_if.1[start..],
+                            );
                         });
                     }
 
-                    if let Some(index) = single_system {
+                    if let Some((index, position)) = single_system {
                         #[cfg(feature = "tracing")]
-                        self.run_single_system(systems, system_names, &parent_span, index)?;
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            &parent_span,
+                            index,
+                            position,
+                            hooks,
+                            batches.on_error.as_ref(),
+                        )?;
                         #[cfg(not(feature = "tracing"))]
-                        self.run_single_system(systems, system_names, index)?;
+                        self.run_single_system(
+                            systems,
+                            system_names,
+                            index,
+                            position,
+                            hooks,
+                            batches.on_error.as_ref(),
+                        )?;
                     }
 
                     Ok(())
@@ -110,6 +211,16 @@ impl World {
                 result?;
             }
 
+            for segment in repeated {
+                if segment.sequential_position >= from_system
+                    && self
+                        .check_conditions(batches, &segment.conditions, &mut conditions)
+                        .map_err(|err| error::RunWorkload::Run((segment.name.clone(), err)))?
+                {
+                    self.run_repeated(systems, system_names, segment, hooks)?;
+                }
+            }
+
             Ok(())
         };
 
@@ -129,36 +240,184 @@ impl World {
         system_names: &[Box<dyn Label>],
         batches: &Batches,
         #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        from_system: usize,
+        hooks: Option<&dyn SystemHooks>,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let parent_span = tracing::info_span!("workload", name = ?workload_name);
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
 
+        let mut conditions = alloc::vec![None; batches.conditions.len()];
+        let mut repeated = batches.repeated.iter().peekable();
+
         batches
             .sequential
             .iter()
-            .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, run_if)| {
-                if let Some(run_if) = run_if.as_ref() {
-                    let should_run = (run_if)(self).map_err(|err| {
-                        error::RunWorkload::Run((system_names[index].clone(), err))
-                    })?;
-
-                    if !should_run {
-                        return Ok(());
+            .enumerate()
+            .skip(from_system)
+            .try_for_each(|(position, &index)| {
+                while let Some(segment) =
+                    repeated.next_if(|segment| segment.sequential_position <= position)
+                {
+                    if segment.sequential_position >= from_system
+                        && self
+                            .check_conditions(batches, &segment.conditions, &mut conditions)
+                            .map_err(|err| error::RunWorkload::Run((segment.name.clone(), err)))?
+                    {
+                        self.run_repeated(systems, system_names, segment, hooks)?;
                     }
                 }
 
+                if !self.should_run(batches, system_names, position, &mut conditions)? {
+                    return Ok(());
+                }
+
                 #[cfg(feature = "tracing")]
                 {
-                    self.run_single_system(systems, system_names, &parent_span, index)
+                    self.run_single_system(
+                        systems,
+                        system_names,
+                        &parent_span,
+                        index,
+                        position,
+                        hooks,
+                        batches.on_error.as_ref(),
+                    )
                 }
                 #[cfg(not(feature = "tracing"))]
                 {
-                    self.run_single_system(systems, system_names, index)
+                    self.run_single_system(
+                        systems,
+                        system_names,
+                        index,
+                        position,
+                        hooks,
+                        batches.on_error.as_ref(),
+                    )
+                }
+            })?;
+
+        for segment in repeated {
+            if segment.sequential_position >= from_system
+                && self
+                    .check_conditions(batches, &segment.conditions, &mut conditions)
+                    .map_err(|err| error::RunWorkload::Run((segment.name.clone(), err)))?
+            {
+                self.run_repeated(systems, system_names, segment, hooks)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the conditions and `run_if` of the system at `position` in `batches.sequential`.
+    fn should_run(
+        &self,
+        batches: &Batches,
+        system_names: &[Box<dyn Label>],
+        position: usize,
+        conditions: &mut [Option<bool>],
+    ) -> Result<bool, error::RunWorkload> {
+        let index = batches.sequential[position];
+
+        let should_run = self
+            .check_conditions(
+                batches,
+                &batches.sequential_conditions[position],
+                conditions,
+            )
+            .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))?;
+
+        if !should_run {
+            return Ok(false);
+        }
+
+        match &batches.sequential_run_if[position] {
+            Some(run_if) => (run_if)(self)
+                .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err))),
+            None => Ok(true),
+        }
+    }
+
+    /// Evaluates shared conditions a single time per run, `cache` keeps their result.
+    fn check_conditions(
+        &self,
+        batches: &Batches,
+        required: &[(usize, bool)],
+        cache: &mut [Option<bool>],
+    ) -> Result<bool, error::Run> {
+        for &(index, expected) in required {
+            let result = match cache[index] {
+                Some(result) => result,
+                None => {
+                    let result = (batches.conditions[index])(self)?;
+                    cache[index] = Some(result);
+
+                    result
                 }
-            })
+            };
+
+            if result != expected {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Runs a repeated workload `count` times.\
+    /// If it fails, the whole segment is run again when the parent workload is resumed.
+    #[allow(clippy::type_complexity)]
+    fn run_repeated(
+        &self,
+        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
+        system_names: &[Box<dyn Label>],
+        repeated: &RepeatedBatches,
+        hooks: Option<&dyn SystemHooks>,
+    ) -> Result<(), error::RunWorkload> {
+        if let Some(run_if) = &repeated.batches.run_if {
+            if !run_if
+                .run(self)
+                .map_err(|err| error::RunWorkload::Run((repeated.name.clone(), err)))?
+            {
+                return Ok(());
+            }
+        }
+
+        for _ in 0..(repeated.count)(self) {
+            #[cfg(feature = "parallel")]
+            let result = self.run_batches_parallel(
+                systems,
+                system_names,
+                &repeated.batches,
+                &*repeated.name,
+                0,
+                hooks,
+            );
+
+            #[cfg(not(feature = "parallel"))]
+            let result = self.run_batches_sequential(
+                systems,
+                system_names,
+                &repeated.batches,
+                &*repeated.name,
+                0,
+                hooks,
+            );
+
+            if let Err(err) = result {
+                // the failed system recorded its position inside the repeated workload
+                // the parent workload has to resume from the start of the segment
+                self.last_failure.store(usize::MAX, Ordering::Relaxed);
+                self.last_failure
+                    .fetch_min(repeated.sequential_position, Ordering::Relaxed);
+
+                return Err(err);
+            }
+        }
+
+        Ok(())
     }
 
     #[allow(clippy::type_complexity)]
@@ -168,6 +427,9 @@ impl World {
         system_names: &[Box<dyn Label>],
         #[cfg(feature = "tracing")] parent_span: &tracing::Span,
         index: usize,
+        position: usize,
+        #[cfg_attr(not(feature = "std"), allow(unused))] hooks: Option<&dyn SystemHooks>,
+        on_error: Option<&ErrorHandler>,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
         let system_span =
@@ -175,7 +437,52 @@ impl World {
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
-        (systems[index])(self)
-            .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+        #[cfg(feature = "alloc_stats")]
+        let start = AllocStats::current_thread();
+        #[cfg(feature = "schedule_trace")]
+        let trace_start = self.schedule_trace.start();
+
+        #[cfg(feature = "std")]
+        let hooks_start = hooks.map(|hooks| {
+            hooks.on_system_start(&*system_names[index]);
+
+            std::time::Instant::now()
+        });
+
+        let result = match (systems[index])(self) {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                let resolution = on_error.map(|on_error| on_error(&*system_names[index], &err));
+
+                if resolution == Some(ErrorResolution::Skip) {
+                    Ok(())
+                } else {
+                    self.last_failure.fetch_min(position, Ordering::Relaxed);
+
+                    Err(error::RunWorkload::Run((system_names[index].clone(), err)))
+                }
+            }
+        };
+
+        #[cfg(feature = "std")]
+        if let Some((hooks, start)) = hooks.zip(hooks_start) {
+            hooks.on_system_end(&*system_names[index], start.elapsed());
+        }
+
+        #[cfg(feature = "schedule_trace")]
+        self.schedule_trace
+            .end(system_names[index].clone(), trace_start);
+
+        #[cfg(feature = "alloc_stats")]
+        {
+            let stats = AllocStats::current_thread().since(start);
+
+            self.alloc_stats
+                .lock()
+                .unwrap()
+                .push((system_names[index].clone(), stats));
+        }
+
+        result
     }
 }
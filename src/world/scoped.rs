@@ -0,0 +1,123 @@
+use crate::borrow::{BorrowInfo, WorldBorrow};
+use crate::error;
+use crate::scheduler::info::TypeInfo;
+use crate::storage::StorageId;
+use crate::system::System;
+use crate::world::World;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+/// Restricted access to a [`World`], only storages in scope can be borrowed.
+///
+/// Created with [`World::scope`].
+pub struct ScopedWorld<'w> {
+    world: &'w World,
+    allowed: &'w [StorageId],
+}
+
+impl World {
+    /// Returns a handle that can only borrow the storages listed in `allowed`.\
+    /// Borrowing any other storage, including [`Entities`](crate::Entities) and [`AllStorages`](crate::AllStorages), fails with [`GetStorage::NotInScope`](error::GetStorage::NotInScope).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, StorageId, View, ViewMut, World};
+    /// use shipyard::sparse_set::SparseSet;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Gold(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let allowed = [StorageId::of::<SparseSet<Health>>()];
+    /// let scoped = world.scope(&allowed);
+    ///
+    /// scoped.run(|_healths: ViewMut<Health>| {});
+    /// assert!(matches!(
+    ///     scoped.borrow::<View<Gold>>(),
+    ///     Err(error::GetStorage::NotInScope { .. })
+    /// ));
+    /// ```
+    pub fn scope<'w>(&'w self, allowed: &'w [StorageId]) -> ScopedWorld<'w> {
+        ScopedWorld {
+            world: self,
+            allowed,
+        }
+    }
+}
+
+impl<'w> ScopedWorld<'w> {
+    /// Returns an error if any storage borrowed by `B` is not in scope.
+    fn check_scope<B: BorrowInfo>(&self) -> Result<(), error::GetStorage> {
+        let mut infos = Vec::new();
+        B::borrow_info(&mut infos);
+
+        for TypeInfo {
+            name, storage_id, ..
+        } in infos
+        {
+            if !self.allowed.contains(&storage_id) {
+                return Err(error::GetStorage::NotInScope {
+                    name: match name {
+                        Cow::Borrowed(name) => Some(name),
+                        Cow::Owned(_) => None,
+                    },
+                    id: storage_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+    /// Borrows the requested storages, if they are all in scope.\
+    /// See [`World::borrow`] for the list of views.
+    ///
+    /// ### Errors
+    ///
+    /// - A storage is not in scope.
+    /// - Any error [`World::borrow`] can return.
+    pub fn borrow<V: WorldBorrow + BorrowInfo>(
+        &self,
+    ) -> Result<V::WorldView<'w>, error::GetStorage> {
+        self.check_scope::<V>()?;
+
+        self.world.borrow::<V>()
+    }
+    /// Borrows the requested storages, runs the function and evaluates to the function's return value.\
+    /// See [`World::run`] for the list of views.
+    ///
+    /// ### Panics
+    ///
+    /// - A storage is not in scope.
+    /// - Any panic [`World::run`] can trigger.
+    #[track_caller]
+    pub fn run<B: BorrowInfo, S: System<(), B>>(&self, system: S) -> S::Return {
+        self.check_scope::<B>()
+            .map_err(error::Run::GetStorage)
+            .unwrap();
+
+        self.world.run(system)
+    }
+    /// Borrows the requested storages, runs the function and evaluates to the function's return value.\
+    /// See [`World::run_with_data`] for the list of views.
+    ///
+    /// ### Panics
+    ///
+    /// - A storage is not in scope.
+    /// - Any panic [`World::run_with_data`] can trigger.
+    #[track_caller]
+    pub fn run_with_data<Data, B: BorrowInfo, S: System<(Data,), B>>(
+        &self,
+        system: S,
+        data: Data,
+    ) -> S::Return {
+        self.check_scope::<B>()
+            .map_err(error::Run::GetStorage)
+            .unwrap();
+
+        self.world.run_with_data(system, data)
+    }
+}
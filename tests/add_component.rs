@@ -162,3 +162,24 @@ fn move_between_worlds() {
     assert_eq!(*world2.get::<&USIZE>(entity1).unwrap(), &USIZE(1));
     assert_eq!(*world2.get::<&U32>(entity1).unwrap(), &U32(2));
 }
+
+#[test]
+fn entry_dead_entity() {
+    let mut world = World::new();
+
+    let dead = world.add_entity(());
+    world.delete_entity(dead);
+    let alive = world.add_entity(());
+
+    let (entities, mut u32s) = world.borrow::<(EntitiesView, ViewMut<U32>)>().unwrap();
+
+    assert!(matches!(
+        u32s.entry(dead).or_insert(&entities, U32(0)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    ));
+    assert_eq!(
+        *u32s.entry(alive).or_insert(&entities, U32(1)).unwrap(),
+        U32(1)
+    );
+    assert!(!u32s.contains(dead));
+}
@@ -133,6 +133,28 @@ fn bulk() {
     assert_eq!(usizes.len(), 4);
 }
 
+#[test]
+fn bulk_with_dead_slots() {
+    let mut world = World::new();
+
+    let dead = world.add_entity(U32(0));
+    world.delete_entity(dead);
+
+    let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+
+    // `filter` has a size hint lower bound of 0, forcing the slow path
+    let new_entities = entities
+        .bulk_add_entity(&mut u32s, (0..3).filter(|_| true).map(U32))
+        .collect::<Vec<_>>();
+
+    assert_eq!(new_entities.len(), 3);
+    for (i, &entity) in new_entities.iter().enumerate() {
+        assert!(entities.is_alive(entity));
+        assert_eq!(u32s[entity], U32(i as u32));
+    }
+    assert!(!entities.is_alive(dead));
+}
+
 #[test]
 fn bulk_unequal_length() {
     #[allow(unused)]
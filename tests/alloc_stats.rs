@@ -0,0 +1,43 @@
+#![cfg(feature = "alloc_stats")]
+
+use shipyard::alloc_stats::CountingAllocator;
+use shipyard::*;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator(std::alloc::System);
+
+fn allocate() {
+    let v: Vec<u64> = std::hint::black_box(Vec::with_capacity(16));
+    drop(v);
+}
+
+fn no_allocation() {}
+
+#[test]
+fn per_system() {
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(allocate)
+        .with_system(no_allocation)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    let stats = world.last_run_alloc_stats();
+    assert_eq!(stats.len(), 2);
+
+    let (_, allocate_stats) = stats
+        .iter()
+        .find(|(label, _)| *label == allocate.as_label())
+        .unwrap();
+    assert_eq!(allocate_stats.allocations, 1);
+    assert_eq!(allocate_stats.bytes, 16 * 8);
+
+    let (_, no_allocation_stats) = stats
+        .iter()
+        .find(|(label, _)| *label == no_allocation.as_label())
+        .unwrap();
+    assert_eq!(no_allocation_stats.allocations, 0);
+}
@@ -72,16 +72,51 @@ fn simple_borrow() {
 
 #[test]
 fn option_borrow() {
-    let world = World::new();
+    let mut world = World::new();
 
     let u32s = world.borrow::<Option<View<U32>>>().unwrap();
-
-    let u32s = u32s.unwrap();
-    assert_eq!(u32s.len(), 0);
+    assert!(u32s.is_none());
     drop(u32s);
+
+    world.add_entity(U32(0));
+
+    let u32s = world.borrow::<Option<View<U32>>>().unwrap();
+    assert_eq!(u32s.unwrap().len(), 1);
+
     let _i32s = world.borrow::<ViewMut<I32>>().unwrap();
-    let other_i32s = world.borrow::<Option<View<I32>>>().unwrap();
-    assert!(other_i32s.is_none());
+    assert_eq!(
+        world.borrow::<Option<View<I32>>>().err(),
+        Some(error::GetStorage::StorageBorrow {
+            name: Some(type_name::<SparseSet<I32>>()),
+            id: StorageId::of::<SparseSet<I32>>(),
+            borrow: error::Borrow::Shared
+        })
+    );
+}
+
+#[test]
+fn option_tuple_borrow() {
+    let mut world = World::new();
+
+    world.add_entity(U32(0));
+
+    let views = world.borrow::<Option<(View<U32>, View<I32>)>>().unwrap();
+    assert!(views.is_none());
+    drop(views);
+
+    // the missing storage wasn't created
+    let i32s = world.borrow::<Option<View<I32>>>().unwrap();
+    assert!(i32s.is_none());
+    drop(i32s);
+
+    world.add_entity(I32(0));
+
+    let (u32s, i32s) = world
+        .borrow::<Option<(View<U32>, View<I32>)>>()
+        .unwrap()
+        .unwrap();
+    assert_eq!(u32s.len(), 1);
+    assert_eq!(i32s.len(), 1);
 }
 
 #[test]
@@ -149,13 +184,22 @@ fn all_storages_option_borrow() {
     let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
 
     let u32s = all_storages.borrow::<Option<View<U32>>>().unwrap();
+    assert!(u32s.is_none());
+
+    all_storages.borrow::<ViewMut<U32>>().unwrap();
+
+    let u32s = all_storages.borrow::<Option<View<U32>>>().unwrap();
+    assert_eq!(u32s.unwrap().len(), 0);
 
-    let u32s = u32s.unwrap();
-    assert_eq!(u32s.len(), 0);
-    drop(u32s);
     let _i32s = all_storages.borrow::<ViewMut<I32>>().unwrap();
-    let other_i32s = all_storages.borrow::<Option<View<I32>>>().unwrap();
-    assert!(other_i32s.is_none());
+    assert_eq!(
+        all_storages.borrow::<Option<View<I32>>>().err(),
+        Some(error::GetStorage::StorageBorrow {
+            name: Some(type_name::<SparseSet<I32>>()),
+            id: StorageId::of::<SparseSet<I32>>(),
+            borrow: error::Borrow::Shared
+        })
+    );
 }
 
 #[test]
@@ -268,3 +312,44 @@ fn unique_exhaustive_list() {
         )>();
     });
 }
+
+#[test]
+fn scoped_world() {
+    let world = World::new();
+
+    let allowed = [
+        StorageId::of::<Entities>(),
+        StorageId::of::<SparseSet<U32>>(),
+    ];
+    let scoped = world.scope(&allowed);
+
+    scoped.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>| {
+        entities.add_entity(&mut u32s, U32(0));
+    });
+
+    assert_eq!(scoped.borrow::<View<U32>>().unwrap().len(), 1);
+    assert_eq!(
+        scoped.borrow::<(View<U32>, View<I32>)>().err(),
+        Some(error::GetStorage::NotInScope {
+            name: Some(type_name::<SparseSet<I32>>()),
+            id: StorageId::of::<SparseSet<I32>>(),
+        })
+    );
+    assert_eq!(
+        scoped.borrow::<AllStoragesViewMut>().err(),
+        Some(error::GetStorage::NotInScope {
+            name: Some(type_name::<AllStorages>()),
+            id: StorageId::of::<AllStorages>(),
+        })
+    );
+}
+
+#[test]
+#[should_panic]
+fn scoped_world_run_out_of_scope() {
+    let world = World::new();
+
+    let allowed = [StorageId::of::<SparseSet<U32>>()];
+
+    world.scope(&allowed).run(|_: View<I32>| {});
+}
@@ -124,3 +124,28 @@ fn inserted() {
     world.run_default_workload().unwrap();
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn clear_entities() {
+    struct Score(u32);
+    impl Unique for Score {}
+
+    let mut world = World::new();
+    world.add_unique(Score(10));
+
+    let entity0 = world.add_entity((U32(0),));
+    world.add_entity((U32(1),));
+
+    world.clear_entities(false);
+    assert!(!world.is_entity_alive(entity0));
+    let entity2 = world.add_entity((U32(2),));
+    assert_ne!(entity2, entity0);
+
+    world.clear_entities(true);
+    assert_eq!(world.add_entity((U32(3),)), entity0);
+
+    world.run(|u32s: View<U32>, score: UniqueView<Score>| {
+        assert_eq!(u32s.len(), 1);
+        assert_eq!(score.0, 10);
+    });
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn applied_at_workload_end() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0),));
+
+    Workload::new("")
+        .with_system(move |mut commands: CommandBufferViewMut| {
+            commands.add_component(entity, (USIZE(1),));
+            commands.remove_component::<(U32,)>(entity);
+        })
+        .with_system(|mut commands: CommandBufferViewMut, u32s: View<U32>| {
+            assert_eq!(u32s.len(), 1);
+            commands.spawn((U32(2),));
+        })
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.iter().collect::<Vec<_>>(), [&U32(2)]);
+        assert_eq!(usizes.get(entity), Ok(&USIZE(1)));
+    });
+}
+
+#[test]
+fn applied_on_error() {
+    fn spawn(mut commands: CommandBufferViewMut) {
+        commands.spawn((U32(0),));
+    }
+
+    fn fail() -> Result<(), error::Run> {
+        Err(error::Run::from_custom("fail"))
+    }
+
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(spawn)
+        .with_barrier()
+        .with_try_system(fail)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.run_default_workload().is_err());
+
+    world.run(|u32s: View<U32>| assert_eq!(u32s.len(), 1));
+}
+
+#[test]
+fn order() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+
+    world.run(|mut commands: CommandBufferViewMut| {
+        commands.add_component(entity, (U32(0),));
+        commands.despawn(entity);
+        commands.add_component(entity, (USIZE(1),));
+    });
+
+    world.run(|mut all_storages: AllStoragesViewMut| all_storages.apply_commands());
+
+    assert!(!world.is_entity_alive(entity));
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert!(u32s.is_empty());
+        assert!(usizes.is_empty());
+    });
+}
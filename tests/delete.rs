@@ -1,5 +1,6 @@
 use core::any::type_name;
 use shipyard::error;
+use shipyard::sparse_set::SparseSet;
 use shipyard::*;
 
 #[derive(PartialEq, Eq, Debug)]
@@ -219,3 +220,28 @@ fn delete_multiple() {
         assert_eq!(u32s.len(), 0, "Second component was not deleted.");
     });
 }
+
+#[test]
+fn delete_component_by_id() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Deletion;
+    }
+
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((USIZE(0), U32(0)));
+    let entity1 = world.add_entity(U32(1));
+
+    world.delete_component_by_id(entity0, StorageId::of::<SparseSet<USIZE>>());
+    world.delete_component_by_id(entity1, StorageId::of::<SparseSet<USIZE>>());
+    world.delete_component_by_id(entity1, StorageId::of::<u8>());
+
+    world.run(|usizes: View<USIZE, track::Deletion>, u32s: View<U32>| {
+        assert!(!usizes.contains(entity0));
+        assert_eq!(usizes.deleted().collect::<Vec<_>>(), [(entity0, &USIZE(0))]);
+        assert_eq!(u32s.get(entity0), Ok(&U32(0)));
+        assert_eq!(u32s.get(entity1), Ok(&U32(1)));
+    });
+}
@@ -111,3 +111,27 @@ fn on_deletion() {
         EntityId::new_from_index_and_gen(0, 0).inner()
     );
 }
+
+#[test]
+fn entity_name() {
+    let mut world = World::new();
+
+    let player = world.add_entity(());
+    let enemy = world.add_entity(());
+    world.set_entity_name(player, "Player");
+    world.set_entity_name(enemy, String::from("Enemy"));
+
+    world.delete_entity(enemy);
+    let new_enemy = world.add_entity(());
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    assert_eq!(
+        format!("{:?}", player.debug_named(&all_storages)),
+        "Player (EId(0.0))"
+    );
+    assert_eq!(all_storages.entity_name(enemy), None);
+    assert_eq!(
+        format!("{:?}", new_enemy.debug_named(&all_storages)),
+        "EId(1.1)"
+    );
+}
@@ -0,0 +1,57 @@
+use shipyard::*;
+
+struct Hit(u32);
+
+#[derive(Unique, Default)]
+struct Received(Vec<u32>);
+
+#[test]
+fn events_in_workload() {
+    fn send(mut hits: EventWriter<Hit>) {
+        hits.push(Hit(1));
+    }
+
+    fn receive(hits: EventReader<Hit>, mut received: UniqueViewMut<Received>) {
+        received.0.extend(hits.read().map(|hit| hit.0));
+    }
+
+    let world = World::new();
+
+    world.add_event::<Hit>();
+    world.add_unique(Received::default());
+
+    Workload::new("")
+        .with_system(receive)
+        .with_system(send)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    assert!(world.borrow::<UniqueView<Received>>().unwrap().0.is_empty());
+
+    world.run(|mut hits: EventWriter<Hit>| hits.push(Hit(2)));
+    world.update_events::<Hit>().unwrap();
+
+    // Each event is only read once by the system
+    world.run_default_workload().unwrap();
+    assert_eq!(
+        world.borrow::<UniqueView<Received>>().unwrap().0,
+        vec![1, 2]
+    );
+
+    world.update_events::<Hit>().unwrap();
+    world.update_events::<Hit>().unwrap();
+
+    world.run_default_workload().unwrap();
+    assert_eq!(
+        world.borrow::<UniqueView<Received>>().unwrap().0,
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn missing_events() {
+    let world = World::new();
+
+    assert!(world.update_events::<Hit>().is_err());
+}
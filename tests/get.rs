@@ -139,3 +139,26 @@ fn old_id() {
         assert!(u32s.get(entity1).is_err());
     });
 }
+
+#[test]
+fn get_or() {
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Untracked;
+    }
+
+    let world = World::new();
+
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>| {
+        let entity = entities.add_entity(&mut u32s, U32(1));
+
+        assert_eq!(u32s.get_or(entity, |u| u.0, 0), 1);
+        assert_eq!(u32s.get_or_else(entity, |u| u.0, || 0), 1);
+
+        entities.delete_unchecked(entity);
+        let entity1 = entities.add_entity((), ());
+
+        assert_eq!(u32s.get_or(entity1, |u| u.0, 0), 0);
+        assert_eq!(u32s.get_or_else(entity1, |u| u.0, || 2), 2);
+    });
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "hierarchy")]
+
+use shipyard::hierarchy::{Children, HierarchyViewMut, Parent};
+use shipyard::{AllStoragesViewMut, Get, View, World};
+
+#[test]
+fn attach_detach() {
+    let mut world = World::new();
+
+    let [root, a, b, c, d] = [(); 5].map(|_| world.add_entity(()));
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+    hierarchy.attach_child(root, a);
+    hierarchy.attach_child(root, b);
+    hierarchy.attach_child(root, c);
+    hierarchy.attach_child(b, d);
+
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [a, b, c]);
+    assert_eq!(
+        hierarchy.descendants(root).collect::<Vec<_>>(),
+        [a, b, d, c]
+    );
+    assert_eq!(hierarchy.ancestors(d).collect::<Vec<_>>(), [b, root]);
+
+    assert_eq!(hierarchy.detach(b), Some(root));
+    assert_eq!(hierarchy.detach(b), None);
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [a, c]);
+    assert_eq!(hierarchy.children(b).collect::<Vec<_>>(), [d]);
+
+    // moves `a` from `root` to `d`
+    hierarchy.attach_child(d, a);
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [c]);
+    assert_eq!(hierarchy.descendants(b).collect::<Vec<_>>(), [d, a]);
+
+    hierarchy.detach(c);
+    drop(hierarchy);
+
+    world.run(|parents: View<Parent>, children: View<Children>| {
+        assert!(!parents.contains(c));
+        assert!(!children.contains(root));
+        assert_eq!(children.get(b).unwrap().len(), 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "would create a cycle")]
+fn attach_cycle() {
+    let mut world = World::new();
+
+    let [a, b] = [(); 2].map(|_| world.add_entity(()));
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+
+    hierarchy.attach_child(a, b);
+    hierarchy.attach_child(b, a);
+}
+
+#[test]
+fn delete_hierarchy() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+
+    let [root, a, b, c] = [(); 4].map(|_| all_storages.add_entity(()));
+
+    {
+        let mut hierarchy = all_storages.borrow::<HierarchyViewMut>().unwrap();
+        hierarchy.attach_child(root, a);
+        hierarchy.attach_child(root, b);
+        hierarchy.attach_child(b, c);
+    }
+
+    assert!(all_storages.delete_hierarchy(b));
+    assert!(!all_storages.delete_hierarchy(b));
+
+    assert!(all_storages.is_entity_alive(a));
+    assert!(!all_storages.is_entity_alive(c));
+
+    let hierarchy = all_storages.borrow::<HierarchyViewMut>().unwrap();
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [a]);
+}
+
+#[test]
+fn delete_child() {
+    let mut world = World::new();
+
+    let [root, a, b, c] = [(); 4].map(|_| world.add_entity(()));
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+        hierarchy.attach_child(root, a);
+        hierarchy.attach_child(root, b);
+    }
+
+    world.delete_entity(a);
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [b]);
+
+    // the links around `a` are still valid
+    hierarchy.attach_child(root, c);
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [b, c]);
+    assert_eq!(hierarchy.detach(b), Some(root));
+    assert_eq!(hierarchy.children(root).collect::<Vec<_>>(), [c]);
+}
+
+#[test]
+fn delete_parent() {
+    let mut world = World::new();
+
+    let [root, a, b] = [(); 3].map(|_| world.add_entity(()));
+
+    {
+        let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+        hierarchy.attach_child(root, a);
+        hierarchy.attach_child(a, b);
+    }
+
+    world.delete_entity(a);
+
+    let mut hierarchy = world.borrow::<HierarchyViewMut>().unwrap();
+    assert_eq!(hierarchy.children(root).count(), 0);
+    assert_eq!(hierarchy.parent(b), None);
+    assert_eq!(hierarchy.detach(b), None);
+}
@@ -193,6 +193,30 @@ fn with_id() {
     assert!(iter.next().is_none());
 }
 
+#[test]
+fn with_id_and_dense_index() {
+    let world = World::new();
+
+    let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+
+    let id0 = entities.add_entity(&mut u32s, U32(0));
+    let id1 = entities.add_entity(&mut u32s, U32(1));
+    let id2 = entities.add_entity(&mut u32s, U32(2));
+    u32s.delete(id0);
+
+    let mut iter = (&u32s).iter().with_id_and_dense_index();
+    assert_eq!(iter.next().unwrap(), (id2, 0, &U32(2)));
+    assert_eq!(iter.next_back().unwrap(), (id1, 1, &U32(1)));
+    assert!(iter.next().is_none());
+
+    let indices = (&mut u32s)
+        .iter()
+        .with_id_and_dense_index()
+        .map(|(id, index, _)| (id, index))
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [(id2, 0), (id1, 1)]);
+}
+
 #[test]
 fn empty() {
     let world = World::new();
@@ -227,6 +251,33 @@ fn not() {
     assert!(iter.next().is_none());
 }
 
+#[test]
+fn multiple_not_with_mut() {
+    let world = World::new();
+
+    let (mut entities, mut u32s, mut i16s, mut usizes) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<I16>, ViewMut<USIZE>)>()
+        .unwrap();
+
+    entities.add_entity((&mut u32s, &mut i16s), (U32(0), I16(10)));
+    let entity1 = entities.add_entity(&mut u32s, U32(1));
+    entities.add_entity((&mut u32s, &mut usizes), (U32(2), USIZE(12)));
+    entities.add_entity(
+        (&mut u32s, &mut i16s, &mut usizes),
+        (U32(3), I16(13), USIZE(13)),
+    );
+
+    for (u32, (), ()) in (&mut u32s, !&i16s, !&usizes).iter() {
+        u32.0 += 10;
+    }
+
+    assert_eq!(u32s[entity1], U32(11));
+    assert_eq!(
+        u32s.iter().copied().collect::<Vec<_>>(),
+        [U32(0), U32(11), U32(2), U32(3)]
+    );
+}
+
 #[test]
 fn or() {
     let mut world = World::new();
@@ -337,3 +388,126 @@ fn vec() {
         assert_eq!(eids, other_eids);
     });
 }
+
+#[test]
+fn copied_cloned() {
+    let world = World::new();
+
+    let (mut entities, mut u32s, mut i16s) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<I16>)>()
+        .unwrap();
+
+    entities.add_entity((&mut u32s, &mut i16s), (U32(0), I16(10)));
+    entities.add_entity(&mut u32s, U32(1));
+    entities.add_entity((&mut u32s, &mut i16s), (U32(2), I16(12)));
+
+    assert_eq!(
+        (&u32s).iter().copied().collect::<Vec<_>>(),
+        [U32(0), U32(1), U32(2)]
+    );
+    assert_eq!(
+        (&u32s).iter().cloned().collect::<Vec<_>>(),
+        [U32(0), U32(1), U32(2)]
+    );
+    assert_eq!(
+        (&u32s, &i16s).iter().copied().collect::<Vec<_>>(),
+        [(U32(0), I16(10)), (U32(2), I16(12))]
+    );
+    assert_eq!(
+        (&u32s, &i16s).iter().cloned().rev().collect::<Vec<_>>(),
+        [(U32(2), I16(12)), (U32(0), I16(10))]
+    );
+}
+
+#[test]
+fn count() {
+    let world = World::new();
+
+    let (mut entities, mut u32s, mut i16s) = world
+        .borrow::<(EntitiesViewMut, ViewMut<U32>, ViewMut<I16>)>()
+        .unwrap();
+
+    entities.add_entity((&mut u32s, &mut i16s), (U32(0), I16(10)));
+    entities.add_entity(&mut u32s, U32(1));
+    entities.add_entity((&mut u32s, &mut i16s), (U32(2), I16(12)));
+    entities.add_entity(&mut i16s, I16(13));
+
+    assert_eq!((&u32s).count(), 3);
+    assert_eq!((&u32s, &i16s).count(), 2);
+    assert_eq!((&u32s, !&i16s).count(), 1);
+    assert_eq!(
+        (&u32s, &i16s).count(),
+        (&u32s, &i16s).iter().collect::<Vec<_>>().len()
+    );
+}
+
+#[test]
+fn ordered_by_id() {
+    let world = World::new();
+
+    let (mut entities, mut usizes, mut u32s) = world
+        .borrow::<(EntitiesViewMut, ViewMut<USIZE>, ViewMut<U32>)>()
+        .unwrap();
+
+    let entity0 = entities.add_entity((), ());
+    let entity1 = entities.add_entity((&mut usizes, &mut u32s), (USIZE(1), U32(1)));
+    let entity2 = entities.add_entity(&mut u32s, U32(2));
+    entities.add_component(entity2, &mut usizes, USIZE(2));
+    entities.add_component(entity0, (&mut usizes, &mut u32s), (USIZE(0), U32(0)));
+
+    for (usize, u32) in (&mut usizes, &mut u32s).iter().ordered_by_id() {
+        usize.0 += 10;
+        u32.0 += 10;
+    }
+
+    let ordered = (&usizes, &u32s)
+        .iter()
+        .ordered_by_id()
+        .with_id()
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ordered,
+        vec![
+            (entity0, (&USIZE(10), &U32(10))),
+            (entity1, (&USIZE(11), &U32(11))),
+            (entity2, (&USIZE(12), &U32(12))),
+        ]
+    );
+}
+
+#[test]
+fn filtered() {
+    let world = World::new();
+
+    let (mut entities, mut usizes, mut u32s) = world
+        .borrow::<(EntitiesViewMut, ViewMut<USIZE>, ViewMut<U32>)>()
+        .unwrap();
+
+    entities.add_entity((&mut usizes, &mut u32s), (USIZE(0), U32(0)));
+    let entity1 = entities.add_entity((&mut usizes, &mut u32s), (USIZE(1), U32(1)));
+    entities.add_entity(&mut u32s, U32(2));
+    let entity3 = entities.add_entity((&mut usizes, &mut u32s), (USIZE(3), U32(3)));
+
+    for (usize, u32) in (&mut usizes, &mut u32s)
+        .iter()
+        .filtered(|(usize, _)| usize.0 % 2 == 1)
+    {
+        usize.0 += 10;
+        u32.0 += 10;
+    }
+
+    let odds = (&usizes, &u32s)
+        .iter()
+        .filtered(|(usize, _)| usize.0 > 10)
+        .with_id()
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        odds,
+        vec![
+            (entity1, (&USIZE(11), &U32(11))),
+            (entity3, (&USIZE(13), &U32(13))),
+        ]
+    );
+}
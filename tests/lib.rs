@@ -438,6 +438,13 @@ fn contains() {
             entities.add_component(entity, &mut u32s, U32(1));
 
             assert!((&usizes, &u32s).contains(entity));
+
+            let checker = (&usizes, &mut u32s).checker();
+            assert!(checker.contains(entity));
+            assert!(!checker.contains(EntityId::new_from_index_and_gen(entity.index(), 1)));
+            assert!(!usizes
+                .checker()
+                .contains(EntityId::new_from_index_and_gen(1, 0)));
         },
     );
 }
@@ -457,3 +464,18 @@ fn debug() {
         );
     });
 }
+
+#[test]
+fn seed() {
+    let order = |seed| {
+        let mut world = World::builder().with_seed(seed).build();
+
+        world.add_entity((USIZE(0), U32(0)));
+        world.add_entity((U32(1),));
+
+        format!("{:?}", world.memory_usage())
+    };
+
+    assert_eq!(order(7), order(7));
+    assert!((0..16).any(|seed| order(seed) != order(7)));
+}
@@ -167,3 +167,24 @@ fn track_reset_with_timestamp() {
     let usizes = world.borrow::<View<USIZE, track::All>>().unwrap();
     assert_eq!(usizes.removed().collect::<Vec<_>>(), vec![]);
 }
+
+#[test]
+fn remove_reporting() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(U32(1));
+    let entity2 = world.add_entity(U32(2));
+
+    let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+
+    assert_eq!(
+        u32s.remove_reporting(entity0),
+        (Some(U32(0)), Some(entity2))
+    );
+    assert_eq!(u32s[entity2], U32(2));
+    assert_eq!(u32s.remove_reporting(entity0), (None, None));
+    assert_eq!(u32s.remove_reporting(entity1), (Some(U32(1)), None));
+    assert_eq!(u32s.remove_reporting(entity2), (Some(U32(2)), None));
+    assert!(u32s.is_empty());
+}
@@ -47,3 +47,53 @@ fn track() {
         assert!(v_u32.is_modified(eid1));
     });
 }
+
+#[test]
+fn retain_entities() {
+    let world = World::new();
+    let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    all_storages.track_all::<U32>();
+
+    let eid0 = all_storages.add_entity(U32(0));
+    let eid1 = all_storages.add_entity(U32(1));
+    let eid2 = all_storages.add_entity(U32(2));
+    let eid3 = all_storages.add_entity(());
+
+    let keep = [eid1, eid2];
+    let deleted = all_storages.retain_entities(|eid| keep.contains(&eid));
+
+    assert_eq!(deleted, 2);
+    assert!(!all_storages.is_entity_alive(eid0));
+    assert!(all_storages.is_entity_alive(eid1));
+    assert!(all_storages.is_entity_alive(eid2));
+    assert!(!all_storages.is_entity_alive(eid3));
+
+    all_storages.run(|v_u32: View<U32, track::All>| {
+        assert!(v_u32.get(eid0).is_err());
+        assert_eq!(v_u32.get(eid1), Ok(&U32(1)));
+        assert_eq!(v_u32.get(eid2), Ok(&U32(2)));
+
+        let mut deleted = v_u32.deleted();
+        assert_eq!(deleted.next(), Some((eid0, &U32(0))));
+        assert!(deleted.next().is_none());
+    });
+
+    assert_eq!(all_storages.retain_entities(|_| true), 0);
+}
+
+#[test]
+fn world_retain_entities_bumps_generation() {
+    let mut world = World::new();
+
+    let eid0 = world.add_entity(U32(0));
+    let eid1 = world.add_entity(U32(1));
+
+    assert_eq!(world.retain_entities(|eid| eid == eid1), 1);
+    assert!(!world.is_entity_alive(eid0));
+
+    let eid2 = world.add_entity(U32(2));
+    assert_eq!(eid2.index(), eid0.index());
+    assert_ne!(eid2.gen(), eid0.gen());
+    assert!(world.get::<&U32>(eid0).is_err());
+    assert_eq!(*world.get::<&U32>(eid2).unwrap(), &U32(2));
+}
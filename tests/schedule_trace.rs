@@ -0,0 +1,32 @@
+#![cfg(feature = "schedule_trace")]
+
+use shipyard::*;
+
+#[derive(Component)]
+struct U32(u32);
+
+fn write(_: ViewMut<U32>) {}
+fn read(_: View<U32>) {}
+
+#[test]
+fn conflicting_systems_do_not_overlap() {
+    let world = World::new();
+
+    Workload::new("")
+        .with_system(write)
+        .with_system(read)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    let trace = world.last_run_schedule_trace();
+    assert_eq!(trace.len(), 2);
+    assert!(trace[0].name == write.as_label());
+    assert!(trace[1].name == read.as_label());
+    assert!(!trace[0].overlaps(&trace[1]));
+    assert!(trace[0].end < trace[1].start);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.last_run_schedule_trace().len(), 2);
+}
@@ -63,3 +63,41 @@ fn update() {
         },
     );
 }
+
+#[test]
+fn apply3_mut() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Untracked;
+    }
+
+    let world = World::new();
+
+    world.run(|mut entities: EntitiesViewMut, mut u32s: ViewMut<U32>| {
+        let entity0 = entities.add_entity(&mut u32s, U32(0));
+        let entity1 = entities.add_entity(&mut u32s, U32(1));
+        let entity2 = entities.add_entity(&mut u32s, U32(2));
+        let entity3 = entities.add_entity((), ());
+
+        u32s.apply3_mut(entity0, entity1, entity2, |a, b, c| {
+            core::mem::swap(a, b);
+            core::mem::swap(b, c);
+        })
+        .unwrap();
+
+        assert_eq!(u32s[entity0], U32(1));
+        assert_eq!(u32s[entity1], U32(2));
+        assert_eq!(u32s[entity2], U32(0));
+
+        // missing components take priority over identical ids
+        assert_eq!(
+            u32s.apply3_mut(entity0, entity0, entity3, |_, _, _| {}),
+            Err(error::Apply::MissingComponent(entity3))
+        );
+        assert_eq!(
+            u32s.apply3_mut(entity0, entity1, entity1, |_, _, _| {}),
+            Err(error::Apply::IdenticalIds)
+        );
+    });
+}
@@ -1,4 +1,4 @@
-use shipyard::{error::GetStorage, track, Component, View, ViewMut, World};
+use shipyard::{error::GetStorage, track, Component, Get, IntoIter, Mut, View, ViewMut, World};
 
 struct Unit;
 impl Component for Unit {
@@ -10,6 +10,12 @@ impl Component for UnitInsert {
     type Tracking = track::Insertion;
 }
 
+#[derive(PartialEq, Eq, Debug)]
+struct Age(u32);
+impl Component for Age {
+    type Tracking = track::InsertionAndModification;
+}
+
 #[test]
 fn runtime_insertion_tracking() {
     let mut world = World::new();
@@ -48,6 +54,37 @@ fn runtime_modification_tracking() {
     assert!(world.borrow::<ViewMut<Unit, track::Modification>>().is_ok());
 }
 
+#[test]
+fn modification_tick_order() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(Age(0));
+    let entity1 = world.add_entity(Age(1));
+
+    world.run(|mut ages: ViewMut<Age>| {
+        assert_eq!(ages.modification_tick(entity0), None);
+        assert!(ages.insertion_tick(entity0).unwrap() < ages.insertion_tick(entity1).unwrap());
+
+        (&mut ages).get(entity1).unwrap().0 += 1;
+    });
+    world.run(|mut ages: ViewMut<Age>| {
+        (&mut ages).get(entity0).unwrap().0 += 1;
+    });
+
+    let ages = world.borrow::<View<Age>>().unwrap();
+    let tick0 = ages.modification_tick(entity0).unwrap();
+    let tick1 = ages.modification_tick(entity1).unwrap();
+    assert!(tick1 < tick0);
+    assert!(tick0 < world.get_tracking_timestamp().get());
+    assert_eq!(
+        world
+            .borrow::<View<Unit>>()
+            .unwrap()
+            .insertion_tick(entity0),
+        None
+    );
+}
+
 #[test]
 fn runtime_deletion_tracking() {
     let mut world = World::new();
@@ -148,3 +185,200 @@ fn workload_enable_runtime_removal_tracking() {
     assert!(world.borrow::<View<Unit, track::Removal>>().is_ok());
     assert!(world.borrow::<ViewMut<Unit, track::Removal>>().is_ok());
 }
+
+#[test]
+fn with_age() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Age(0));
+    world.add_entity(Age(1));
+
+    {
+        let mut ages = world.borrow::<ViewMut<Age>>().unwrap();
+        (&mut ages).get(e0).unwrap().0 = 10;
+    }
+
+    let current = world.get_tracking_timestamp();
+    let ages = world.borrow::<View<Age>>().unwrap();
+    let mut iter = ages.iter().with_age(current);
+
+    assert_eq!(iter.len(), 2);
+    // e0 was modified by the previous borrow
+    assert_eq!(iter.next(), Some((&Age(10), 1)));
+    // e1 was never modified, its age starts at its insertion
+    assert_eq!(iter.next(), Some((&Age(1), 2)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn with_age_without_modification_tracking() {
+    let mut world = World::new();
+
+    world.add_entity(UnitInsert);
+
+    let current = world.get_tracking_timestamp();
+    let units = world.borrow::<View<UnitInsert>>().unwrap();
+    let _ = units.iter().with_age(current);
+}
+
+#[test]
+fn iter_changed() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Age(0));
+    world.add_entity(Age(1));
+
+    world.borrow::<ViewMut<Age>>().unwrap().clear_all_inserted();
+
+    let e2 = world.add_entity(Age(2));
+    (&mut world.borrow::<ViewMut<Age>>().unwrap())
+        .get(e0)
+        .unwrap()
+        .0 = 10;
+
+    let ages = world.borrow::<ViewMut<Age>>().unwrap();
+    let mut changed = ages.iter_changed().with_id().collect::<Vec<_>>();
+    changed.sort_unstable_by_key(|(eid, _)| *eid);
+
+    assert_eq!(changed, vec![(e0, &Age(10)), (e2, &Age(2))]);
+}
+
+#[test]
+fn compare_modification() {
+    #[derive(Component, Clone, PartialEq, Debug)]
+    #[track(Modification)]
+    #[shipyard(compare)]
+    struct Position(f32, f32);
+
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Position(0.0, 0.0));
+    let e1 = world.add_entity(Position(1.0, 1.0));
+
+    world.run(|mut positions: ViewMut<Position>| {
+        for mut position in (&mut positions).iter() {
+            position.0 += 0.0;
+        }
+
+        (&mut positions).get(e1).unwrap().1 = 5.0;
+
+        assert!(!positions.is_modified(e0));
+        assert!(positions.is_modified(e1));
+    });
+
+    world.run(|positions: ViewMut<Position>| positions.clear_all_modified());
+
+    world.run(|mut positions: ViewMut<Position>| {
+        let mut position = (&mut positions).get(e1).unwrap();
+        position.1 = 2.0;
+        position.1 = 5.0;
+        drop(position);
+
+        let position = (&mut positions).get(e0).unwrap();
+        Mut::map(position, |position| &mut position.1);
+
+        assert!(!positions.is_modified(e1));
+        assert!(!positions.is_modified(e0));
+
+        let position = (&mut positions).get(e0).unwrap();
+        *Mut::map(position, |position| &mut position.1) = 2.0;
+
+        assert!(positions.is_modified(e0));
+    });
+}
+
+#[test]
+fn update_or_remove_modification() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Age(1));
+
+    world.run(|mut ages: ViewMut<Age>| {
+        assert_eq!(ages.update_or_remove(entity, |age| age.0 > 0), None);
+        assert!(!ages.is_modified(entity));
+
+        let kept = ages.update_or_remove(entity, |mut age| {
+            age.0 += 1;
+            true
+        });
+        assert_eq!(kept, None);
+        assert!(ages.is_modified(entity));
+
+        assert_eq!(ages.update_or_remove(entity, |_| false), Some(Age(2)));
+        assert!(!ages.is_modified(entity));
+    });
+}
+
+#[test]
+fn strip_tracks_removal() {
+    #[derive(Component)]
+    #[track(All)]
+    struct Tracked(u32);
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((Tracked(0), Unit));
+
+    world.strip(entity);
+
+    assert!(world.is_entity_alive(entity));
+    world.run(|tracked: View<Tracked>| {
+        assert_eq!(tracked.removed().collect::<Vec<_>>(), [entity]);
+        assert_eq!(tracked.deleted().count(), 0);
+    });
+}
+
+#[test]
+fn clear_single_entity() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Age(0));
+    let e1 = world.add_entity(Age(1));
+
+    let mut ages = world.borrow::<ViewMut<Age>>().unwrap();
+
+    ages.clear_inserted(e0);
+    assert!(!ages.is_inserted(e0));
+    assert!(ages.is_inserted(e1));
+
+    (&mut ages).get(e0).unwrap().0 = 10;
+    (&mut ages).get(e1).unwrap().0 = 11;
+
+    ages.clear_modified(e1);
+    assert!(ages.is_modified(e0));
+    assert!(!ages.is_modified(e1));
+
+    ages.clear_inserted_and_modified(e1);
+    let changed = ages.iter_changed().with_id().collect::<Vec<_>>();
+    assert_eq!(changed, vec![(e0, &Age(10))]);
+}
+
+#[test]
+fn stable_sort_tracking() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity(Age(3));
+    let e1 = world.add_entity(Age(1));
+    let e2 = world.add_entity(Age(2));
+    let e3 = world.add_entity(Age(0));
+
+    let mut ages = world.borrow::<ViewMut<Age>>().unwrap();
+
+    ages.clear_inserted(e0);
+    ages.clear_inserted(e2);
+    (&mut ages).get(e0).unwrap().0 = 30;
+    (&mut ages).get(e3).unwrap().0 = 0;
+
+    ages.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(ages.iter().ids().collect::<Vec<_>>(), [e3, e1, e2, e0]);
+    assert!(!ages.is_inserted(e0));
+    assert!(ages.is_inserted(e1));
+    assert!(!ages.is_inserted(e2));
+    assert!(ages.is_inserted(e3));
+    assert!(ages.is_modified(e0));
+    assert!(!ages.is_modified(e1));
+    assert!(!ages.is_modified(e2));
+    assert!(ages.is_modified(e3));
+}
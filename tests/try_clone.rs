@@ -0,0 +1,55 @@
+#![cfg(feature = "std")]
+
+use shipyard::error;
+use shipyard::sparse_set::SparseSet;
+use shipyard::*;
+
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+#[track(Insertion)]
+#[shipyard(clone)]
+struct U32(u32);
+
+#[derive(Unique, Clone, Debug, PartialEq, Eq)]
+#[shipyard(clone)]
+struct USIZE(usize);
+
+struct NotClone;
+impl Component for NotClone {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn try_clone() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    world.add_unique(USIZE(2));
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_inserted());
+    let entity1 = world.add_entity(U32(3));
+
+    let clone = world.try_clone().unwrap();
+
+    world.delete_entity(entity0);
+    world.run(|mut usize: UniqueViewMut<USIZE>| usize.0 = 4);
+
+    clone.run(|u32s: View<U32>, usize: UniqueView<USIZE>| {
+        assert_eq!(u32s[entity0], U32(0));
+        assert_eq!(u32s[entity1], U32(3));
+        assert!(!u32s.is_inserted(entity0));
+        assert!(u32s.is_inserted(entity1));
+        assert_eq!(*usize, USIZE(2));
+    });
+}
+
+#[test]
+fn try_clone_not_clone() {
+    let mut world = World::new();
+
+    world.add_entity((U32(0), NotClone));
+
+    assert!(matches!(
+        world.try_clone(),
+        Err(error::CloneWorld::NotClone { id, .. }) if id == StorageId::of::<SparseSet<NotClone>>()
+    ));
+}
@@ -211,3 +211,20 @@ fn unique_or_init_mut() {
 
     world.run(|u: UniqueOrInitViewMut<USIZE>| assert_eq!(**u.get().unwrap(), USIZE(11)));
 }
+
+#[test]
+fn get_or_add_unique() {
+    let world = World::new();
+
+    world.get_or_add_unique(|| USIZE(10)).unwrap().0 += 1;
+    world
+        .get_or_add_unique::<USIZE, _>(|| unreachable!())
+        .unwrap()
+        .0 += 1;
+
+    assert_eq!(*world.borrow::<UniqueView<USIZE>>().unwrap(), USIZE(12));
+
+    let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    let _unique = all_storages.get_or_add_unique(|| USIZE(0)).unwrap();
+    assert!(all_storages.get_or_add_unique(|| USIZE(0)).is_err());
+}
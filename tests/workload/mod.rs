@@ -249,3 +249,452 @@ fn skip_first() {
 
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn different_uniques_run_in_parallel() {
+    fn sum(usizes: View<USIZE>, mut sum: UniqueViewMut<U32>) {
+        sum.0 = usizes.iter().map(|i| i.0 as u32).sum();
+    }
+
+    fn count(usizes: View<USIZE>, mut count: UniqueViewMut<USIZE>) {
+        count.0 = usizes.iter().count();
+    }
+
+    let mut world = World::new();
+
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+    world.bulk_add_entity((0..4).map(USIZE));
+
+    let (workload, info) = Workload::new("")
+        .with_system(sum)
+        .with_system(count)
+        .build()
+        .unwrap();
+
+    assert_eq!(info.batch_info.len(), 1);
+    assert_eq!(info.batch_info[0].systems().count(), 2);
+
+    workload.run_with_world(&world).unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 6);
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 4);
+}
+
+#[test]
+fn resume_workload() {
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    fn fallible(usize: UniqueView<USIZE>) -> Result<(), error::Run> {
+        if usize.0 == 0 {
+            Err(error::Run::from_custom("USIZE is 0"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn add_ten(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 10;
+    }
+
+    let world = World::new();
+
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    Workload::new("")
+        .with_system(increment)
+        .with_try_system(fallible)
+        .with_system(add_ten)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.run_default_workload().is_err());
+    assert_eq!(world.last_failed_system(), Some(1));
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    world.borrow::<UniqueViewMut<USIZE>>().unwrap().0 = 1;
+
+    world.resume_workload("", 1).unwrap();
+    assert_eq!(world.last_failed_system(), None);
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 11);
+}
+
+#[test]
+fn branch() {
+    fn flip(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 = 1 - usize.0;
+    }
+
+    fn add_one(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    fn add_ten(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 10;
+    }
+
+    let world = World::new();
+
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    Workload::new("")
+        .with_workload(Workload::branch(
+            |usize: UniqueView<USIZE>| usize.0 == 0,
+            (flip, add_one).into_workload(),
+            (flip, add_ten).into_workload(),
+        ))
+        .add_to_world(&world)
+        .unwrap();
+
+    // Only the branches' systems are scheduled
+    assert_eq!(world.workload_system_labels("").unwrap().len(), 4);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 1);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 11);
+}
+
+#[test]
+fn group_run_if() {
+    fn disable(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 = 0;
+    }
+
+    fn add_one(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    fn add_ten(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 10;
+    }
+
+    let world = World::new();
+
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(1));
+
+    Workload::new("")
+        .with_workload(
+            Workload::group(
+                (
+                    disable,
+                    add_one,
+                    add_ten.skip_if(|u32: UniqueView<U32>| u32.0 > 10),
+                )
+                    .into_sequential_workload(),
+            )
+            .run_if(|usize: UniqueView<USIZE>| usize.0 != 0),
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.workload_system_labels("").unwrap(),
+        [disable.as_label(), add_one.as_label(), add_ten.as_label()]
+    );
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 11);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 11);
+
+    world.borrow::<UniqueViewMut<USIZE>>().unwrap().0 = 1;
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<U32>>().unwrap().0, 12);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn max_threads() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+
+    let world = World::new();
+
+    let mut workload = Workload::new("");
+    for _ in 0..8 {
+        let running = running.clone();
+        let max_running = max_running.clone();
+
+        workload = workload.with_system(move || {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    workload.with_max_threads(2).add_to_world(&world).unwrap();
+
+    world.run_default_workload().unwrap();
+
+    assert!(max_running.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn repeated_workload() {
+    struct Count(usize);
+    impl Unique for Count {}
+
+    struct Repeat(usize);
+    impl Unique for Repeat {}
+
+    fn increment(mut count: UniqueViewMut<Count>) {
+        count.0 += 1;
+    }
+
+    fn double(mut count: UniqueViewMut<Count>) {
+        count.0 *= 2;
+    }
+
+    let world = World::new();
+    world.add_unique(Count(0));
+    world.add_unique(Repeat(3));
+
+    Workload::new("main")
+        .with_system(increment)
+        .with_repeated_workload(
+            Workload::new("inner")
+                .with_system(double)
+                .with_system(increment.after_all(double)),
+            |world| world.borrow::<UniqueView<Repeat>>().unwrap().0,
+        )
+        .with_system(double)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.workload_system_labels("main").unwrap(),
+        [
+            increment.as_label(),
+            double.as_label(),
+            increment.as_label(),
+            double.as_label()
+        ]
+    );
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 30);
+
+    world.borrow::<UniqueViewMut<Repeat>>().unwrap().0 = 0;
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 62);
+}
+
+#[test]
+fn workload_system_labels() {
+    fn sys1() {}
+    fn sys2() {}
+
+    let world = World::new();
+
+    Workload::new("a")
+        .with_system(sys1)
+        .with_system(sys2.after_all(sys1))
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("b")
+        .with_system(sys2)
+        .with_system(sys1.after_all(sys2))
+        .add_to_world(&world)
+        .unwrap();
+
+    let a = world.workload_system_labels("a").unwrap();
+    let b = world.workload_system_labels("b").unwrap();
+
+    assert_eq!(a, [sys1.as_label(), sys2.as_label()]);
+    assert_eq!(b, [sys2.as_label(), sys1.as_label()]);
+    assert!(world.workload_system_labels("c").is_none());
+}
+
+#[test]
+fn try_merge() {
+    fn sys1() {}
+    fn sys2() {}
+    fn sys3() {}
+
+    let world = World::new();
+
+    Workload::new("merged")
+        .with_system(sys1)
+        .with_system(sys2)
+        .try_merge(Workload::new("other").with_system(sys2).with_system(sys3))
+        .unwrap()
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.workload_system_labels("merged").unwrap(),
+        [sys1.as_label(), sys2.as_label(), sys3.as_label()]
+    );
+
+    let result = Workload::new("conflict")
+        .with_system(sys1.display_name("sys"))
+        .try_merge(Workload::new("other").with_system(sys2.display_name("sys")));
+
+    assert_eq!(
+        result.err(),
+        Some(error::InvalidSystem::ConflictingName("sys".into()))
+    );
+}
+
+#[test]
+fn replace_in_world() {
+    struct Count(u32);
+    impl Unique for Count {}
+
+    fn add_one(mut count: UniqueViewMut<Count>) {
+        count.0 += 1;
+    }
+    fn add_ten(mut count: UniqueViewMut<Count>) {
+        count.0 += 10;
+    }
+
+    let world = World::new();
+    world.add_unique(Count(0));
+
+    Workload::new("update")
+        .with_system(add_one)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("update")
+        .with_system(add_ten)
+        .replace_in_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 10);
+    assert_eq!(
+        world.workload_system_labels("update").unwrap(),
+        [add_ten.as_label()]
+    );
+}
+
+#[test]
+fn run_if_changed() {
+    struct Count(u32);
+    impl Unique for Count {}
+
+    struct A(u32);
+    impl Component for A {
+        type Tracking = track::InsertionAndModification;
+    }
+
+    struct B(u32);
+    impl Component for B {
+        type Tracking = track::Modification;
+    }
+
+    fn validate(mut count: UniqueViewMut<Count>) {
+        count.0 += 1;
+    }
+
+    let mut world = World::new();
+    world.add_unique(Count(0));
+
+    Workload::new("validation")
+        .with_system(validate)
+        .run_if_changed::<(A, B)>()
+        .add_to_world(&world)
+        .unwrap();
+
+    let count = |world: &World| world.borrow::<UniqueView<Count>>().unwrap().0;
+
+    world.run_default_workload().unwrap();
+    assert_eq!(count(&world), 0);
+
+    let entity = world.add_entity((A(0), B(0)));
+    world.run_default_workload().unwrap();
+    assert_eq!(count(&world), 1);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(count(&world), 1);
+
+    world.get::<&mut B>(entity).unwrap().0 += 1;
+    world.run_default_workload().unwrap();
+    assert_eq!(count(&world), 2);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(count(&world), 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn on_error() {
+    struct Count(u32);
+    impl Unique for Count {}
+
+    fn fail() -> Result<(), &'static str> {
+        Err("fail")
+    }
+    fn add_one(mut count: UniqueViewMut<Count>) {
+        count.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(Count(0));
+
+    Workload::new("skip")
+        .with_try_system(fail)
+        .with_barrier()
+        .with_system(add_one)
+        .on_error(|system, _| {
+            assert_eq!(system, &*fail.as_label());
+
+            ErrorResolution::Skip
+        })
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("abort")
+        .with_try_system(fail)
+        .with_barrier()
+        .with_system(add_one)
+        .on_error(|_, _| ErrorResolution::Abort)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("skip").unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+
+    assert!(world.run_workload("abort").is_err());
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+}
+
+#[test]
+fn workload_to_dot() {
+    struct Position(f32);
+    impl Component for Position {
+        type Tracking = track::Untracked;
+    }
+
+    fn read(_: View<Position>) {}
+    fn write(_: ViewMut<Position>) {}
+
+    let world = World::new();
+
+    Workload::new("Move")
+        .with_system(read)
+        .with_system(write)
+        .add_to_world(&world)
+        .unwrap();
+
+    let dot = world.workload_to_dot("Move").unwrap();
+
+    assert!(dot.starts_with("digraph "));
+    assert!(dot.contains("Move"));
+    assert!(dot.contains("subgraph cluster_0"));
+    assert!(dot.contains("subgraph cluster_1"));
+    assert!(dot.contains("b0_0 -> b1_0"));
+    assert!(world.workload_to_dot("Other").is_none());
+}
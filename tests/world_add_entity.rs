@@ -172,3 +172,84 @@ fn bulk() {
     assert_eq!(u32s.len(), 10);
     assert_eq!(usizes.len(), 10);
 }
+
+#[test]
+fn clone_entity() {
+    #[derive(Component, Clone, Debug, PartialEq, Eq)]
+    #[shipyard(clone)]
+    struct Name(&'static str);
+
+    struct Handle;
+    impl Component for Handle {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(Name("a"));
+    let clone = world.clone_entity(entity).unwrap();
+    assert_ne!(clone, entity);
+    assert_eq!(*world.get::<&Name>(clone).unwrap(), Name("a"));
+
+    world.add_component(entity, Handle);
+    assert!(matches!(
+        world.clone_entity(entity),
+        Err(error::CloneEntity::NotClone(names)) if names.len() == 1
+    ));
+    assert_eq!(world.borrow::<View<Name>>().unwrap().len(), 2);
+
+    world.delete_entity(entity);
+    assert_eq!(
+        world.clone_entity(entity),
+        Err(error::CloneEntity::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn spawn_columns() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((USIZE(0), U32(0)));
+    let entities = world
+        .spawn_columns(((1..4).map(USIZE), (1..4).map(U32)))
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(entities.len(), 3);
+
+    assert_eq!(
+        world
+            .spawn_columns(((4..6).map(USIZE), (4..7).map(U32)))
+            .err(),
+        Some(error::SpawnColumns::MismatchedLength {
+            expected: 2,
+            found: 3,
+            column: 1
+        })
+    );
+    assert_eq!(
+        world
+            .spawn_columns(((4..6).map(USIZE), (4..6).map(U32), (6..8).map(USIZE)))
+            .err(),
+        Some(error::SpawnColumns::DuplicateComponent(
+            core::any::type_name::<USIZE>()
+        ))
+    );
+
+    let (usizes, u32s) = world.borrow::<(View<USIZE>, View<U32>)>().unwrap();
+    assert_eq!(usizes.len(), 4);
+    assert_eq!(u32s.len(), 4);
+    assert_eq!((&usizes, &u32s).get(entity0), Ok((&USIZE(0), &U32(0))));
+    for (i, entity) in entities.into_iter().enumerate() {
+        assert_eq!(
+            (&usizes, &u32s).get(entity),
+            Ok((&USIZE(i + 1), &U32(i as u32 + 1)))
+        );
+    }
+}
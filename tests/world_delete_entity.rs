@@ -76,3 +76,20 @@ fn update() {
     );
     assert_eq!(usizes.removed().count(), 0);
 }
+
+#[test]
+fn is_alive() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    assert!(world.is_alive(entity));
+
+    world.delete_entity(entity);
+    assert!(!world.is_alive(entity));
+
+    let reused = world.add_entity(());
+    assert_eq!(reused.index(), entity.index());
+    assert!(world.is_alive(reused));
+    assert!(!world.is_alive(entity));
+    assert!(world.all_storages().unwrap().is_alive(reused));
+}